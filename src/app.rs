@@ -1,4 +1,4 @@
-use crate::components::Component;
+use crate::components::{Component, StatusMessage};
 use crate::docker::DockerClient;
 use crate::{
     ui_containers::ContainersUI, ui_images::ImagesUI, ui_networks::NetworksUI,
@@ -10,15 +10,27 @@ use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use futures::{FutureExt, StreamExt};
 use ratatui::{Terminal, backend::CrosstermBackend};
 use std::{io, sync::Arc};
-use tokio::sync::{Mutex, mpsc};
+use tokio::sync::{Mutex, Notify, mpsc};
 use tokio_util::sync::CancellationToken;
 
+// Output floods (`yes` in the shell pane) redraw at most this often
+const MIN_FRAME_INTERVAL: std::time::Duration = std::time::Duration::from_millis(16);
+
 #[derive(Debug)]
 pub enum AppEvent {
     // Key events
     Key(KeyEvent),
     // Error events (only global errors now)
     Error(String),
+    // Suspend the TUI and run an interactive shell in the container
+    ExecShell {
+        container_id: String,
+        container_name: String,
+    },
+    // Switch to the tab listing this container and select it
+    ShowContainer {
+        container_id: String,
+    },
 }
 
 pub struct App {
@@ -26,29 +38,43 @@ pub struct App {
     pub should_quit: bool,
     // UI modules
     pub components: Vec<Box<dyn Component>>,
+    pub status_message: Option<StatusMessage>,
+    docker_client: Arc<Mutex<DockerClient>>,
     // Event handling
     event_rx: mpsc::UnboundedReceiver<AppEvent>,
     event_tx: mpsc::UnboundedSender<AppEvent>,
+    // Background output (shell pane) waiting to be drawn, notifications
+    // that arrive before the next frame collapse into one redraw
+    redraw: Arc<Notify>,
     cancellation_token: CancellationToken,
+    input_token: CancellationToken,
+    input_task: Option<tokio::task::JoinHandle<()>>,
 }
 
 impl App {
     pub async fn new(ip: Option<String>) -> Result<Self> {
         let (event_tx, event_rx) = mpsc::unbounded_channel();
         let cancellation_token = CancellationToken::new();
+        let redraw = Arc::new(Notify::new());
 
         // Initialize shared Docker client
-        let docker_client = if ip.is_none() {
-            Arc::new(Mutex::new(DockerClient::new().await?))
+        let docker_client = if let Some(ip) = ip {
+            Arc::new(Mutex::new(DockerClient::connect(&ip, 5).await?))
         } else {
-            Arc::new(Mutex::new(DockerClient::connect(&ip.unwrap(), 5).await?))
+            Arc::new(Mutex::new(DockerClient::new().await?))
         };
 
         // Initialize UI modules with shared Docker client
-        let containers_ui = ContainersUI::new(Arc::clone(&docker_client), 0);
-        let images_ui = ImagesUI::new(Arc::clone(&docker_client), 1);
-        let networks_ui = NetworksUI::new(Arc::clone(&docker_client), 2);
-        let volumes_ui = VolumesUI::new(docker_client, 3);
+        let containers_ui = ContainersUI::new(
+            Arc::clone(&docker_client),
+            0,
+            cancellation_token.clone(),
+            event_tx.clone(),
+            Arc::clone(&redraw),
+        );
+        let images_ui = ImagesUI::new(Arc::clone(&docker_client), 1, cancellation_token.clone());
+        let networks_ui = NetworksUI::new(Arc::clone(&docker_client), 2, event_tx.clone());
+        let volumes_ui = VolumesUI::new(Arc::clone(&docker_client), 3);
 
         let components: Vec<Box<dyn Component>> = vec![
             Box::new(containers_ui),
@@ -61,8 +87,13 @@ impl App {
             active_tab: 0,
             should_quit: false,
             components,
+            status_message: None,
+            docker_client,
             event_rx,
             event_tx,
+            redraw,
+            input_token: cancellation_token.child_token(),
+            input_task: None,
             cancellation_token,
         })
     }
@@ -77,26 +108,40 @@ impl App {
         }
 
         // Start input task
-        self.start_input_task()?;
+        self.start_input_task();
 
-        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
+        // Short tick so streamed content (logs) shows up promptly
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_millis(250));
 
         // Main event loop
         while !self.should_quit {
             // Draw the UI
             terminal.draw(|frame| crate::ui::draw_ui(frame, self))?;
+            let last_draw = tokio::time::Instant::now();
 
             tokio::select! {
                 _ = interval.tick() => {
                     for component in &mut self.components {
                         component.tick().await;
                     }
+                    if self
+                        .status_message
+                        .as_ref()
+                        .is_some_and(|message| message.is_expired())
+                    {
+                        self.status_message = None;
+                    }
+                }
+
+                // Only draw, components are ticked by the interval
+                _ = self.redraw.notified() => {
+                    tokio::time::sleep_until(last_draw + MIN_FRAME_INTERVAL).await;
                 }
 
                 // Handle events
                 event = self.event_rx.recv() => {
                     if let Some(event) = event {
-                        self.handle_event(event).await?;
+                        self.handle_event(event, &mut terminal).await?;
                     }
                 }
             }
@@ -109,7 +154,11 @@ impl App {
         Ok(())
     }
 
-    async fn handle_event(&mut self, event: AppEvent) -> Result<()> {
+    async fn handle_event(
+        &mut self,
+        event: AppEvent,
+        terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    ) -> Result<()> {
         match event {
             AppEvent::Key(key) => {
                 // First try to delegate to active UI module
@@ -119,8 +168,13 @@ impl App {
                     .iter_mut()
                     .find(|c| c.tab() == self.active_tab)
                 {
+                    // Focused panes get the raw event first, bypassing global keys
+                    handled = component.handle_key_event(key).await.unwrap_or(false);
+
                     // Component returns true if it handled the event
-                    handled = component.handle_input(key.code).await.unwrap_or(false);
+                    if !handled {
+                        handled = component.handle_input(key.code).await.unwrap_or(false);
+                    }
                 }
 
                 // If component didn't handle it, try global keys
@@ -129,13 +183,76 @@ impl App {
                 }
             }
             AppEvent::Error(error) => {
-                // Log global errors
-                eprintln!("Application error: {}", error);
+                // Show global errors in the help line
+                self.status_message = Some(StatusMessage::error(error));
+            }
+            AppEvent::ExecShell {
+                container_id,
+                container_name,
+            } => {
+                self.run_exec_shell(terminal, &container_id, &container_name)
+                    .await?;
+            }
+            AppEvent::ShowContainer { container_id } => {
+                let mut found = false;
+                for component in &mut self.components {
+                    if component.select(&container_id).await {
+                        self.active_tab = component.tab();
+                        found = true;
+                        break;
+                    }
+                }
+                if !found {
+                    self.status_message = Some(StatusMessage::error(format!(
+                        "Container {} not found",
+                        DockerClient::format_container_id(&container_id)
+                    )));
+                }
             }
         }
         Ok(())
     }
 
+    /// Hand the real terminal to a container shell, then restore the TUI
+    async fn run_exec_shell(
+        &mut self,
+        terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+        container_id: &str,
+        container_name: &str,
+    ) -> Result<()> {
+        // Stop reading keys so the shell gets stdin
+        self.input_token.cancel();
+        if let Some(input_task) = self.input_task.take() {
+            let _ = input_task.await;
+        }
+
+        // Raw mode stays on: keys (Ctrl+C included) go to the container TTY
+        crossterm::execute!(
+            terminal.backend_mut(),
+            crossterm::terminal::LeaveAlternateScreen,
+            crossterm::terminal::Clear(crossterm::terminal::ClearType::All),
+            crossterm::cursor::MoveTo(0, 0),
+            crossterm::cursor::Show
+        )?;
+
+        let client = self.docker_client.lock().await.clone();
+        let result = crate::exec::run_interactive_shell(client, container_id).await;
+
+        crossterm::execute!(
+            terminal.backend_mut(),
+            crossterm::terminal::EnterAlternateScreen
+        )?;
+        terminal.clear()?;
+        self.start_input_task();
+
+        self.status_message = Some(match result {
+            Ok(()) => StatusMessage::info(format!("Shell in {} exited", container_name)),
+            Err(e) => StatusMessage::error(format!("Exec in {} failed: {}", container_name, e)),
+        });
+
+        Ok(())
+    }
+
     fn handle_global_key_event(&mut self, key: KeyEvent) -> bool {
         match key.code {
             KeyCode::Char('q') => {
@@ -166,11 +283,12 @@ impl App {
         }
     }
 
-    fn start_input_task(&self) -> Result<()> {
+    fn start_input_task(&mut self) {
         let event_tx = self.event_tx.clone();
-        let cancellation_token = self.cancellation_token.clone();
+        self.input_token = self.cancellation_token.child_token();
+        let cancellation_token = self.input_token.clone();
 
-        tokio::spawn(async move {
+        self.input_task = Some(tokio::spawn(async move {
             let mut reader = crossterm::event::EventStream::new();
 
             loop {
@@ -183,17 +301,15 @@ impl App {
                             Some(Ok(crossterm::event::Event::Key(key))) => {
                                 let _ = event_tx.send(AppEvent::Key(key));
                             }
-                            Some(Err(_)) => {
-                                // Handle error if needed
+                            Some(Err(e)) => {
+                                let _ = event_tx.send(AppEvent::Error(format!("Input error: {}", e)));
                             }
                             _ => {}
                         }
                     }
                 }
             }
-        });
-
-        Ok(())
+        }));
     }
 
     fn init_terminal(&self) -> Result<Terminal<CrosstermBackend<io::Stdout>>> {
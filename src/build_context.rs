@@ -0,0 +1,401 @@
+use bytes::Bytes;
+use color_eyre::eyre::{Result, WrapErr, eyre};
+use regex::Regex;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tokio::sync::mpsc;
+
+// Name given to a Dockerfile that lives outside the context directory
+const EXTERNAL_DOCKERFILE: &str = ".rustocker.Dockerfile";
+
+// Size of the chunks the archive is sent in
+const CHUNK_SIZE: usize = 256 * 1024;
+
+/// Build context with paths resolved and `.dockerignore` loaded. The tar is
+/// written on the fly by `pack`, so the context never has to fit in memory
+pub struct BuildContext {
+    context_dir: PathBuf,
+    dockerfile_path: PathBuf,
+    ignore: DockerIgnore,
+    /// Path of the Dockerfile inside the archive
+    pub dockerfile: String,
+}
+
+struct IgnorePattern {
+    regex: Regex,
+    negated: bool,
+}
+
+/// `.dockerignore` rules, same semantics as the docker CLI:
+/// patterns are relative to the context root, `**` spans directories,
+/// `!` re-includes and the last matching pattern wins
+struct DockerIgnore {
+    patterns: Vec<IgnorePattern>,
+}
+
+impl DockerIgnore {
+    fn load(context_dir: &Path) -> Result<Self> {
+        let path = context_dir.join(".dockerignore");
+        match fs::read_to_string(&path) {
+            Ok(content) => Self::parse(&content),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::parse(""),
+            Err(e) => Err(e).wrap_err_with(|| format!("Cannot read {}", path.display())),
+        }
+    }
+
+    fn parse(content: &str) -> Result<Self> {
+        let mut patterns = Vec::new();
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (negated, pattern) = match line.strip_prefix('!') {
+                Some(rest) => (true, rest.trim()),
+                None => (false, line),
+            };
+            let pattern = Self::clean(pattern);
+            if pattern.is_empty() {
+                continue;
+            }
+            let regex = Regex::new(&Self::to_regex(&pattern))
+                .wrap_err_with(|| format!("Invalid .dockerignore pattern {}", line))?;
+            patterns.push(IgnorePattern { regex, negated });
+        }
+
+        Ok(Self { patterns })
+    }
+
+    // Like Go's filepath.Clean, without the leading slash
+    fn clean(pattern: &str) -> String {
+        let mut parts: Vec<&str> = Vec::new();
+        for part in pattern.split('/') {
+            match part {
+                "" | "." => {}
+                ".." => {
+                    parts.pop();
+                }
+                part => parts.push(part),
+            }
+        }
+        parts.join("/")
+    }
+
+    fn to_regex(pattern: &str) -> String {
+        let mut regex = String::from("^");
+        let mut chars = pattern.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '*' if chars.peek() == Some(&'*') => {
+                    chars.next();
+                    // "**/" also matches no directory at all
+                    if chars.peek() == Some(&'/') {
+                        chars.next();
+                        regex.push_str("(.*/)?");
+                    } else {
+                        regex.push_str(".*");
+                    }
+                }
+                '*' => regex.push_str("[^/]*"),
+                '?' => regex.push_str("[^/]"),
+                '\\' => {
+                    if let Some(escaped) = chars.next() {
+                        regex.push_str(&regex::escape(&escaped.to_string()));
+                    }
+                }
+                '[' => {
+                    regex.push('[');
+                    if chars.peek() == Some(&'^') || chars.peek() == Some(&'!') {
+                        chars.next();
+                        regex.push('^');
+                    }
+                    for c in chars.by_ref() {
+                        if c == ']' {
+                            break;
+                        }
+                        if c == '\\' || c == '[' {
+                            regex.push('\\');
+                        }
+                        regex.push(c);
+                    }
+                    regex.push(']');
+                }
+                c => regex.push_str(&regex::escape(&c.to_string())),
+            }
+        }
+
+        regex.push('$');
+        regex
+    }
+
+    fn has_exceptions(&self) -> bool {
+        self.patterns.iter().any(|pattern| pattern.negated)
+    }
+
+    /// `path` is relative to the context root, with `/` separators.
+    /// A pattern matching a parent directory excludes its content too
+    fn is_excluded(&self, path: &str) -> bool {
+        let mut excluded = false;
+        for pattern in &self.patterns {
+            let matches = pattern.regex.is_match(path)
+                || path
+                    .match_indices('/')
+                    .any(|(index, _)| pattern.regex.is_match(&path[..index]));
+            if matches {
+                excluded = !pattern.negated;
+            }
+        }
+        excluded
+    }
+}
+
+impl BuildContext {
+    /// Resolve the context directory and the Dockerfile, which may live
+    /// outside the context; it's added to the archive anyway
+    pub fn new(context_dir: &Path, dockerfile: &Path) -> Result<Self> {
+        let context_dir = context_dir
+            .canonicalize()
+            .wrap_err_with(|| format!("Context {} not found", context_dir.display()))?;
+        if !context_dir.is_dir() {
+            return Err(eyre!(
+                "Context {} is not a directory",
+                context_dir.display()
+            ));
+        }
+
+        // A relative Dockerfile path is relative to the context, like `docker build -f`
+        let dockerfile = if dockerfile.is_absolute() {
+            dockerfile.to_path_buf()
+        } else {
+            context_dir.join(dockerfile)
+        };
+        let dockerfile_path = dockerfile
+            .canonicalize()
+            .wrap_err_with(|| format!("Dockerfile {} not found", dockerfile.display()))?;
+        let dockerfile = match dockerfile_path.strip_prefix(&context_dir) {
+            Ok(relative) => to_tar_path(relative),
+            Err(_) => EXTERNAL_DOCKERFILE.to_string(),
+        };
+
+        let ignore = DockerIgnore::load(&context_dir)?;
+        Ok(Self {
+            context_dir,
+            dockerfile_path,
+            ignore,
+            dockerfile,
+        })
+    }
+
+    /// Tar the context into `writer`, skipping what `.dockerignore` excludes.
+    /// Returns the number of files
+    pub fn pack<W: Write>(&self, writer: W) -> Result<usize> {
+        let mut builder = tar::Builder::new(writer);
+        builder.follow_symlinks(false);
+        let mut files = 0;
+
+        add_directory(
+            &mut builder,
+            &self.context_dir,
+            &self.context_dir,
+            &self.ignore,
+            &mut files,
+        )?;
+
+        // The daemon always needs the Dockerfile and .dockerignore, even if ignored
+        if self.dockerfile == EXTERNAL_DOCKERFILE || self.ignore.is_excluded(&self.dockerfile) {
+            builder
+                .append_path_with_name(&self.dockerfile_path, &self.dockerfile)
+                .wrap_err_with(|| format!("Cannot read {}", self.dockerfile_path.display()))?;
+            files += 1;
+        }
+        let dockerignore = self.context_dir.join(".dockerignore");
+        if dockerignore.is_file() && self.ignore.is_excluded(".dockerignore") {
+            builder.append_path_with_name(&dockerignore, ".dockerignore")?;
+            files += 1;
+        }
+
+        builder
+            .into_inner()
+            .and_then(|mut writer| writer.flush())
+            .wrap_err("Cannot create context archive")?;
+
+        Ok(files)
+    }
+}
+
+/// `Write` end of a streamed request body: the archive goes out in chunks
+/// through a bounded channel, blocking while the daemon catches up.
+/// Fails with `BrokenPipe` once the request is dropped
+pub struct ChunkWriter {
+    tx: mpsc::Sender<std::io::Result<Bytes>>,
+    buffer: Vec<u8>,
+    written: u64,
+}
+
+impl ChunkWriter {
+    pub fn new(tx: mpsc::Sender<std::io::Result<Bytes>>) -> Self {
+        Self {
+            tx,
+            buffer: Vec::with_capacity(CHUNK_SIZE),
+            written: 0,
+        }
+    }
+
+    /// Bytes handed to the request so far
+    pub fn written(&self) -> u64 {
+        self.written
+    }
+
+    fn send(&mut self) -> std::io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let chunk = std::mem::replace(&mut self.buffer, Vec::with_capacity(CHUNK_SIZE));
+        self.written += chunk.len() as u64;
+        self.tx.blocking_send(Ok(chunk.into())).map_err(|_| {
+            std::io::Error::new(std::io::ErrorKind::BrokenPipe, "build request closed")
+        })
+    }
+}
+
+impl Write for ChunkWriter {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        self.buffer.extend_from_slice(data);
+        if self.buffer.len() >= CHUNK_SIZE {
+            self.send()?;
+        }
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.send()
+    }
+}
+
+fn add_directory<W: Write>(
+    builder: &mut tar::Builder<W>,
+    root: &Path,
+    dir: &Path,
+    ignore: &DockerIgnore,
+    files: &mut usize,
+) -> Result<()> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)
+        .wrap_err_with(|| format!("Cannot read {}", dir.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<_>>()?;
+    // Stable order keeps the build cache happy
+    entries.sort();
+
+    for path in entries {
+        let Ok(relative) = path.strip_prefix(root) else {
+            continue;
+        };
+        let relative = to_tar_path(relative);
+        let excluded = ignore.is_excluded(&relative);
+        let metadata = fs::symlink_metadata(&path)
+            .wrap_err_with(|| format!("Cannot read {}", path.display()))?;
+
+        if metadata.is_dir() {
+            // Walk excluded directories only when a "!" pattern may re-include something
+            if excluded && !ignore.has_exceptions() {
+                continue;
+            }
+            if !excluded {
+                builder.append_path_with_name(&path, &relative)?;
+            }
+            add_directory(builder, root, &path, ignore, files)?;
+        } else if !excluded {
+            builder
+                .append_path_with_name(&path, &relative)
+                .wrap_err_with(|| format!("Cannot read {}", path.display()))?;
+            *files += 1;
+        }
+    }
+
+    Ok(())
+}
+
+fn to_tar_path(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ignore(content: &str) -> DockerIgnore {
+        DockerIgnore::parse(content).expect("valid patterns")
+    }
+
+    #[test]
+    fn double_star_spans_directories() {
+        let ignore = ignore("**/*.log\ndocs/**\na/**/b");
+        assert!(ignore.is_excluded("debug.log"));
+        assert!(ignore.is_excluded("x/y/debug.log"));
+        assert!(!ignore.is_excluded("debug.txt"));
+        assert!(ignore.is_excluded("docs/guide/intro.md"));
+        assert!(!ignore.is_excluded("src/docs"));
+        assert!(ignore.is_excluded("a/b"));
+        assert!(ignore.is_excluded("a/x/y/b"));
+        assert!(!ignore.is_excluded("a/x/c"));
+    }
+
+    #[test]
+    fn single_star_stays_in_one_directory() {
+        let ignore = ignore("*.tmp\nsrc/*.rs");
+        assert!(ignore.is_excluded("a.tmp"));
+        assert!(!ignore.is_excluded("dir/a.tmp"));
+        assert!(ignore.is_excluded("src/main.rs"));
+        assert!(!ignore.is_excluded("src/bin/main.rs"));
+    }
+
+    #[test]
+    fn negation_re_includes_and_last_match_wins() {
+        let ignore_md = ignore("*.md\n!README.md");
+        assert!(ignore_md.is_excluded("CHANGELOG.md"));
+        assert!(!ignore_md.is_excluded("README.md"));
+        assert!(ignore_md.has_exceptions());
+
+        let reordered = ignore("!README.md\n*.md");
+        assert!(reordered.is_excluded("README.md"));
+    }
+
+    #[test]
+    fn leading_slash_is_relative_to_the_root() {
+        let ignore = ignore("/build");
+        assert!(ignore.is_excluded("build"));
+        assert!(ignore.is_excluded("build/out.o"));
+        assert!(!ignore.is_excluded("src/build"));
+    }
+
+    #[test]
+    fn trailing_slash_matches_the_directory_and_its_content() {
+        let ignore = ignore("target/");
+        assert!(ignore.is_excluded("target"));
+        assert!(ignore.is_excluded("target/debug/app"));
+        assert!(!ignore.is_excluded("targets"));
+    }
+
+    #[test]
+    fn comments_and_blank_lines_are_skipped() {
+        let ignore = ignore("# comment\n\n   \n.git");
+        assert_eq!(ignore.patterns.len(), 1);
+        assert!(ignore.is_excluded(".git/config"));
+        assert!(!ignore.is_excluded("# comment"));
+    }
+
+    #[test]
+    fn patterns_translate_to_anchored_regexes() {
+        assert_eq!(DockerIgnore::to_regex("**/*.rs"), "^(.*/)?[^/]*\\.rs$");
+        assert_eq!(
+            DockerIgnore::to_regex("file?.[!a-c]"),
+            "^file[^/]\\.[^a-c]$"
+        );
+        assert_eq!(DockerIgnore::to_regex("a\\*b"), "^a\\*b$");
+    }
+}
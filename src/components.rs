@@ -1,10 +1,10 @@
 use async_trait::async_trait;
 use color_eyre::Result;
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::Frame;
 
 #[async_trait]
-pub(crate) trait Component {
+pub(crate) trait Component: Send {
     fn name(&self) -> &str;
 
     fn tab(&self) -> usize;
@@ -13,6 +13,59 @@ pub(crate) trait Component {
     async fn tick(&mut self);
     async fn handle_input(&mut self, key: KeyCode) -> Result<bool>;
 
+    /// Full key event with modifiers, offered before any other handling.
+    /// Used by panes that need every key (embedded terminal).
+    async fn handle_key_event(&mut self, _key: KeyEvent) -> Result<bool> {
+        Ok(false)
+    }
+
+    /// Select the item with this ID, for jumps from other tabs.
+    /// False when the component doesn't list it
+    async fn select(&mut self, _id: &str) -> bool {
+        false
+    }
+
     fn render(&self, f: &mut Frame, area: ratatui::layout::Rect);
     fn render_help(&self) -> &'static str;
 }
+
+/// Transient feedback line shown at the bottom of a tab after an operation
+#[derive(Debug, Clone)]
+pub(crate) struct StatusMessage {
+    pub text: String,
+    pub is_error: bool,
+    created: std::time::Instant,
+}
+
+impl StatusMessage {
+    const TTL_SECS: u64 = 8;
+
+    pub fn info(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            is_error: false,
+            created: std::time::Instant::now(),
+        }
+    }
+
+    pub fn error(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            is_error: true,
+            created: std::time::Instant::now(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.created.elapsed().as_secs() >= Self::TTL_SECS
+    }
+
+    pub fn style(&self) -> ratatui::style::Style {
+        let theme = crate::theme::current_theme();
+        if self.is_error {
+            theme.error_style()
+        } else {
+            theme.success_style()
+        }
+    }
+}
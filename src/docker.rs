@@ -1,22 +1,193 @@
+use bollard::auth::DockerCredentials;
+use bollard::container::LogOutput;
+use bollard::exec::{StartExecOptions, StartExecResults};
+use bollard::models::BuildInfo;
+use bollard::models::ExecConfig;
 use bollard::models::ImageSummary;
-use bollard::models::SystemVersion;
+use bollard::models::PortSummary;
+use bollard::models::ProgressDetail;
+use bollard::models::{ContainerCpuStats, ContainerStatsResponse};
+use bollard::models::{Ipam, IpamConfig, NetworkCreateRequest};
 use bollard::query_parameters::{
-    ListContainersOptions, ListImagesOptionsBuilder, ListNetworksOptionsBuilder,
-    ListVolumesOptionsBuilder,
+    BuildImageOptionsBuilder, CreateImageOptions, CreateImageOptionsBuilder,
+    ImportImageOptionsBuilder, KillContainerOptionsBuilder, ListContainersOptionsBuilder,
+    ListImagesOptionsBuilder, ListNetworksOptionsBuilder, ListVolumesOptionsBuilder,
+    LogsOptionsBuilder, PruneImagesOptionsBuilder, RemoveContainerOptionsBuilder,
+    RemoveImageOptionsBuilder, ResizeExecOptionsBuilder, RestartContainerOptionsBuilder,
+    StartContainerOptions, StatsOptionsBuilder, StopContainerOptionsBuilder,
+    TagImageOptionsBuilder,
 };
-use bollard::{API_DEFAULT_VERSION, Docker};
+use bollard::{Docker, body_try_stream};
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
 use std::collections::HashMap;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+#[derive(Debug, Clone)]
+pub struct ContainerInfo {
+    pub id: String,              // Full ID per operazioni
+    pub display_id: String,      // Troncato per display
+    pub name: String,            // Primo nome senza "/"
+    pub image: String,           // "nginx:latest"
+    pub command: String,         // "nginx -g 'daemon off;'"
+    pub state: String,           // "running", "exited", ...
+    pub status: String,          // "Up 2 hours"
+    pub created_ago: String,     // "2d"
+    pub ports: String,           // "8080->80/tcp, 443/tcp"
+    pub compose_project: String, // Progetto compose o "-"
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ContainerStats {
+    pub cpu_percent: f64,  // Come `docker stats`, 100% per core
+    pub memory_usage: u64, // Senza page cache
+    pub memory_limit: u64,
+    pub net_rx: u64, // Totali dall'avvio
+    pub net_tx: u64,
+    pub block_read: u64,
+    pub block_write: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogSource {
+    Stdout,
+    Stderr,
+}
+
+#[derive(Debug, Clone)]
+pub struct LogLine {
+    pub source: LogSource,
+    pub timestamp: String, // "2024-05-01 12:34:56" o vuoto
+    pub message: String,
+}
+
+// A line longer than this is cut rather than buffered forever
+const MAX_PENDING_LINE: usize = 1024 * 1024;
+
+/// Splits log frames into lines. A frame can end mid-line (TTY output,
+/// large writes), so the unterminated tail of each stream is kept and
+/// prepended to the next frame of the same stream
+#[derive(Debug, Default)]
+pub struct LogDecoder {
+    stdout: Vec<u8>, // Anche l'output console (TTY)
+    stderr: Vec<u8>,
+}
+
+impl LogDecoder {
+    pub fn decode(&mut self, output: &LogOutput) -> Vec<LogLine> {
+        let source = match output {
+            LogOutput::StdErr { .. } => LogSource::Stderr,
+            _ => LogSource::Stdout,
+        };
+        let pending = match source {
+            LogSource::Stdout => &mut self.stdout,
+            LogSource::Stderr => &mut self.stderr,
+        };
+        pending.extend_from_slice(output.as_ref());
+
+        let complete: Vec<u8> = match pending.iter().rposition(|&byte| byte == b'\n') {
+            Some(end) => pending.drain(..=end).collect(),
+            None if pending.len() > MAX_PENDING_LINE => std::mem::take(pending),
+            None => return Vec::new(),
+        };
+        String::from_utf8_lossy(&complete)
+            .lines()
+            .map(|line| DockerClient::parse_log_line(source, line))
+            .collect()
+    }
+
+    /// Lines left without a final newline, once the stream has ended
+    pub fn finish(&mut self) -> Vec<LogLine> {
+        [
+            (LogSource::Stdout, std::mem::take(&mut self.stdout)),
+            (LogSource::Stderr, std::mem::take(&mut self.stderr)),
+        ]
+        .into_iter()
+        .filter(|(_, pending)| !pending.is_empty())
+        .map(|(source, pending)| {
+            DockerClient::parse_log_line(source, &String::from_utf8_lossy(&pending))
+        })
+        .collect()
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct ImageInfo {
     pub id: String,               // Full ID per operazioni
     pub display_id: String,       // Troncato per display
     pub repo_tag: String,         // "nginx:latest" o "<none>:<none>"
+    pub repo_tags: Vec<String>,   // Tutti i tag, vuoto se non taggata
+    pub size: i64,                // Byte, per stime e confronti
     pub size_formatted: String,   // "142.3 MB"
     pub created_ago: String,      // "2d"
+    pub containers: i64,          // Container che la usano, anche fermi
     pub containers_count: String, // "3" o "-"
 }
 
+/// Image list filters, dangling and label are applied by the daemon
+#[derive(Debug, Clone, Default)]
+pub struct ImageFilters {
+    pub dangling: bool,        // Solo immagini senza tag
+    pub unused: bool,          // Solo immagini senza container
+    pub label: Option<String>, // "key" o "key=value"
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworkInfo {
+    pub id: String,          // Full ID per operazioni
+    pub display_id: String,  // Troncato per display
+    pub name: String,        // "bridge", "myapp_default"
+    pub driver: String,      // "bridge", "overlay", ...
+    pub scope: String,       // "local", "swarm" o "global"
+    pub subnet: String,      // "172.17.0.0/16", separate da ", ", "-" se nessuna
+    pub gateway: String,     // "172.17.0.1", "-" se nessuno
+    pub internal: bool,      // Senza accesso all'esterno
+    pub attachable: bool,    // Container standalone ammessi su reti swarm
+    pub containers: usize,   // Container collegati, anche fermi
+    pub created: i64,        // Unix timestamp, per ordinamento
+    pub created_ago: String, // "2d"
+}
+
+/// Settings of a network to create, empty strings are left to the daemon
+#[derive(Debug, Clone, Default)]
+pub struct NetworkConfig {
+    pub name: String,
+    pub driver: String,                   // "bridge", "overlay" o "macvlan"
+    pub subnet: String,                   // CIDR, "172.20.0.0/16"
+    pub gateway: String,                  // Dentro la subnet
+    pub ip_range: String,                 // CIDR dentro la subnet
+    pub internal: bool,                   // Senza accesso all'esterno
+    pub attachable: bool,                 // Container standalone ammessi su reti swarm
+    pub enable_ipv6: bool,                // Necessario per subnet IPv6
+    pub labels: HashMap<String, String>,  // Metadati della rete
+    pub options: HashMap<String, String>, // Opzioni del driver, "parent" per macvlan
+}
+
+#[derive(Debug, Clone)]
+pub struct ProgressUpdate {
+    pub id: String,     // ID del layer, vuoto per messaggi generali
+    pub status: String, // "Downloading", "Pull complete", ...
+    pub current: u64,   // Byte, 0 se sconosciuti
+    pub total: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImageLayer {
+    pub id: String,             // "sha256:..." o "<missing>" per layer di base
+    pub created_by: String,     // Istruzione, senza "/bin/sh -c #(nop) "
+    pub size: i64,              // Byte, per confronti e ordinamento
+    pub size_formatted: String, // "12.4 MB"
+    pub created_ago: String,    // "3mo"
+    pub tags: Vec<String>,
+    pub comment: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum BuildOutput {
+    Line(String),    // Output di uno step, può contenere più righe
+    ImageId(String), // "sha256:..." dell'immagine costruita
+}
+
 #[derive(Debug, Clone)]
 pub struct ImageInspectDetails {
     pub id: String,
@@ -31,22 +202,103 @@ pub struct ImageInspectDetails {
     pub entrypoint: Vec<String>,
     pub cmd: Vec<String>,
     pub labels: HashMap<String, String>,
+    pub layers: Vec<String>, // Digest dei layer dal base in su
 }
 
+#[derive(Debug, Clone)]
+pub struct MountDetails {
+    pub kind: String, // "bind", "volume", "tmpfs"
+    pub source: String,
+    pub destination: String,
+    pub mode: String, // "rw" o "ro"
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworkEndpointDetails {
+    pub name: String,
+    pub ip_address: String, // "172.17.0.2/16"
+    pub gateway: String,
+    pub ipv6_address: String,
+    pub mac_address: String,
+    pub aliases: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IpamConfigDetails {
+    pub subnet: String,   // "172.18.0.0/16"
+    pub ip_range: String, // Vuoto se tutta la subnet
+    pub gateway: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworkContainerDetails {
+    pub id: String,
+    pub name: String,
+    pub ipv4_address: String, // "172.18.0.2/16", vuoto se assente
+    pub ipv6_address: String,
+    pub mac_address: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworkInspectDetails {
+    pub id: String,
+    pub name: String,
+    pub driver: String,
+    pub scope: String,
+    pub created_formatted: String,
+    pub internal: bool,
+    pub attachable: bool,
+    pub ingress: bool,
+    pub enable_ipv6: bool,
+    pub ipam_driver: String, // "default" salvo plugin
+    pub ipam_config: Vec<IpamConfigDetails>,
+    pub options: HashMap<String, String>,
+    pub labels: HashMap<String, String>,
+    pub containers: Vec<NetworkContainerDetails>, // Ordinati per nome
+}
+
+#[derive(Debug, Clone)]
+pub struct ContainerInspectDetails {
+    pub id: String,
+    pub name: String,
+    pub image: String,
+    pub created_formatted: String,
+    pub state: String,      // "running", "exited (137)", ...
+    pub started_at: String, // Vuoto se mai avviato
+    pub finished_at: String,
+    pub restart_policy: String, // "on-failure:3", "unless-stopped", ...
+    pub restart_count: i64,
+    pub health: Option<String>,  // "healthy", "unhealthy (3 failing)"
+    pub health_log: Vec<String>, // Output degli ultimi check
+    pub hostname: String,
+    pub user: String,
+    pub working_dir: String,
+    pub entrypoint: Vec<String>,
+    pub cmd: Vec<String>,
+    pub env: Vec<String>,
+    pub mounts: Vec<MountDetails>,
+    pub networks: Vec<NetworkEndpointDetails>,
+    pub port_bindings: Vec<String>, // "0.0.0.0:8080 -> 80/tcp"
+    pub memory_limit: String,       // "unlimited" se 0
+    pub cpu_limit: String,
+    pub pids_limit: String,
+    pub labels: HashMap<String, String>,
+}
+
+#[derive(Clone)]
 pub struct DockerClient {
     docker: Docker,
-    pub version: SystemVersion,
 }
 
 impl DockerClient {
     pub async fn new() -> Result<Self, bollard::errors::Error> {
         // Try to connect to Docker daemon
         let docker = Docker::connect_with_local_defaults()?;
+        // Older daemons refuse the API version bollard is generated from,
+        // asking the daemon for its version also checks it's reachable
+        let docker = docker.negotiate_version().await?;
 
-        // get version
-        let version = docker.version().await?;
-
-        Ok(Self { docker, version })
+        Ok(Self { docker })
     }
 
     pub async fn connect(host: &str, timeout: u64) -> Result<Self, bollard::errors::Error> {
@@ -55,52 +307,192 @@ impl DockerClient {
 
         // Connect using HTTP (no SSL)
         let docker = Docker::connect_with_http(&host_url, timeout, bollard::API_DEFAULT_VERSION)?;
+        let docker = docker.negotiate_version().await?;
 
-        // get version
-        let version = docker.version().await?;
+        Ok(Self { docker })
+    }
 
-        Ok(Self { docker, version })
+    pub async fn list_containers(&self) -> Result<Vec<ContainerInfo>, bollard::errors::Error> {
+        self.list_containers_filtered(HashMap::new()).await
     }
 
-    pub async fn list_containers(&self) -> Result<Vec<String>, bollard::errors::Error> {
-        let options = Some(ListContainersOptions {
-            all: true,
-            ..Default::default()
-        });
+    /// Containers created from the image or one of its descendants
+    pub async fn containers_using_image(
+        &self,
+        image_id: &str,
+    ) -> Result<Vec<ContainerInfo>, bollard::errors::Error> {
+        self.list_containers_filtered(HashMap::from([("ancestor", vec![image_id])]))
+            .await
+    }
+
+    async fn list_containers_filtered(
+        &self,
+        filters: HashMap<&str, Vec<&str>>,
+    ) -> Result<Vec<ContainerInfo>, bollard::errors::Error> {
+        let options = ListContainersOptionsBuilder::new()
+            .all(true)
+            .filters(&filters)
+            .build();
 
-        let containers = self.docker.list_containers(options).await?;
+        let containers = self.docker.list_containers(Some(options)).await?;
 
         Ok(containers
             .into_iter()
-            .filter_map(|container| {
+            .map(|container| {
+                let id = container.id.unwrap_or_default();
+
                 // Get the first name (without the leading slash)
-                container.names.and_then(|names| {
-                    names
-                        .into_iter()
-                        .next()
-                        .map(|name| name.trim_start_matches('/').to_string())
-                })
+                let name = container
+                    .names
+                    .and_then(|names| names.into_iter().next())
+                    .map(|name| name.trim_start_matches('/').to_string())
+                    .unwrap_or_else(|| Self::format_container_id(&id));
+
+                let compose_project = container
+                    .labels
+                    .as_ref()
+                    .and_then(|labels| labels.get("com.docker.compose.project"))
+                    .cloned()
+                    .unwrap_or_else(|| "-".to_string());
+
+                ContainerInfo {
+                    display_id: Self::format_container_id(&id),
+                    id,
+                    name,
+                    image: container.image.unwrap_or_default(),
+                    command: container.command.unwrap_or_default(),
+                    state: container
+                        .state
+                        .map(|state| state.to_string())
+                        .unwrap_or_else(|| "unknown".to_string()),
+                    status: container.status.unwrap_or_default(),
+                    created_ago: container
+                        .created
+                        .map(Self::format_time_ago)
+                        .unwrap_or_else(|| "-".to_string()),
+                    ports: Self::format_ports(&container.ports.unwrap_or_default()),
+                    compose_project,
+                }
             })
             .collect())
     }
 
-    pub async fn list_images(&self) -> Result<Vec<ImageInfo>, bollard::errors::Error> {
-        let options = ListImagesOptionsBuilder::new().all(true).build();
+    pub async fn list_images(
+        &self,
+        filters: &ImageFilters,
+    ) -> Result<Vec<ImageInfo>, bollard::errors::Error> {
+        // Intermediate images of the legacy builder are untagged too,
+        // only top-level ones count as dangling, like `docker images -f`
+        self.list_images_with(!filters.dangling, filters).await
+    }
+
+    async fn list_images_with(
+        &self,
+        all: bool,
+        filters: &ImageFilters,
+    ) -> Result<Vec<ImageInfo>, bollard::errors::Error> {
+        let mut api_filters: HashMap<&str, Vec<&str>> = HashMap::new();
+        if filters.dangling {
+            api_filters.insert("dangling", vec!["true"]);
+        }
+        if let Some(label) = &filters.label {
+            api_filters.insert("label", vec![label.as_str()]);
+        }
+        let options = ListImagesOptionsBuilder::new()
+            .all(all)
+            .filters(&api_filters)
+            .build();
         let images = self.docker.list_images(Some(options)).await?;
 
+        // The daemon computes container counts only for `docker system df`
+        let usage = if images.iter().any(|img| img.containers < 0) {
+            Some(self.image_usage().await?)
+        } else {
+            None
+        };
+
         Ok(images
             .into_iter()
-            .map(|img| ImageInfo {
+            .map(|img| {
+                let containers = match &usage {
+                    Some(usage) => usage.get(&img.id).copied().unwrap_or(0),
+                    None => img.containers,
+                };
+                (img, containers)
+            })
+            .filter(|(_, containers)| !filters.unused || *containers == 0)
+            .map(|(img, containers)| ImageInfo {
                 id: img.id.clone(),
                 display_id: Self::format_image_id(&img),
                 repo_tag: Self::format_image_name(&img),
+                repo_tags: img
+                    .repo_tags
+                    .iter()
+                    .filter(|tag| tag.as_str() != "<none>:<none>")
+                    .cloned()
+                    .collect(),
+                size: img.size,
                 size_formatted: Self::format_size(img.size),
                 created_ago: Self::format_time_ago(img.created),
-                containers_count: Self::format_containers_count(img.containers),
+                containers,
+                containers_count: Self::format_containers_count(containers),
             })
             .collect())
     }
 
+    // Image ID -> number of containers created from it, running or not
+    async fn image_usage(&self) -> Result<HashMap<String, i64>, bollard::errors::Error> {
+        let options = ListContainersOptionsBuilder::new().all(true).build();
+        let containers = self.docker.list_containers(Some(options)).await?;
+
+        let mut usage = HashMap::new();
+        for image_id in containers.into_iter().filter_map(|c| c.image_id) {
+            *usage.entry(image_id).or_insert(0) += 1;
+        }
+        Ok(usage)
+    }
+
+    /// Top-level images without containers, what `prune_images` removes
+    /// with `all`. The untagged ones are the dangling images
+    pub async fn prune_candidates(
+        &self,
+        label: Option<&str>,
+    ) -> Result<Vec<ImageInfo>, bollard::errors::Error> {
+        let filters = ImageFilters {
+            dangling: false,
+            unused: true,
+            label: label.map(str::to_string),
+        };
+        self.list_images_with(false, &filters).await
+    }
+
+    /// Daemon report, as `remove_image`, with the reclaimed bytes
+    pub async fn prune_images(
+        &self,
+        all: bool,
+        label: Option<&str>,
+    ) -> Result<(Vec<String>, i64), bollard::errors::Error> {
+        let dangling = if all { "false" } else { "true" };
+        let mut filters = HashMap::from([("dangling", vec![dangling])]);
+        if let Some(label) = label {
+            filters.insert("label", vec![label]);
+        }
+        let options = PruneImagesOptionsBuilder::new().filters(&filters).build();
+        let response = self.docker.prune_images(Some(options)).await?;
+
+        let report = response
+            .images_deleted
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|item| {
+                let untagged = item.untagged.map(|tag| format!("Untagged: {}", tag));
+                let deleted = item.deleted.map(|id| format!("Deleted: {}", id));
+                untagged.into_iter().chain(deleted)
+            })
+            .collect();
+        Ok((report, response.space_reclaimed.unwrap_or(0)))
+    }
+
     pub async fn inspect_image(
         &self,
         image_id: &str,
@@ -108,17 +500,9 @@ impl DockerClient {
         let inspect_result = self.docker.inspect_image(image_id).await?;
 
         // Format creation time
-        let created_formatted = if let Some(created) = &inspect_result.created {
-            use chrono::{DateTime, Utc};
-            if let Ok(dt) = DateTime::parse_from_rfc3339(created) {
-                dt.with_timezone(&Utc)
-                    .format("%Y-%m-%d %H:%M:%S UTC")
-                    .to_string()
-            } else {
-                created.clone()
-            }
-        } else {
-            "Unknown".to_string()
+        let created_formatted = match &inspect_result.created {
+            Some(created) => Self::format_datetime(created),
+            None => "Unknown".to_string(),
         };
 
         // Extract environment variables
@@ -131,7 +515,7 @@ impl DockerClient {
         // Extract exposed ports
         let exposed_ports = if let Some(config) = &inspect_result.config {
             if let Some(exposed_ports) = &config.exposed_ports {
-                exposed_ports.keys().cloned().collect()
+                exposed_ports.clone()
             } else {
                 Vec::new()
             }
@@ -166,6 +550,12 @@ impl DockerClient {
             HashMap::new()
         };
 
+        // Diff IDs, the same base shares a prefix of them
+        let layers = inspect_result
+            .root_fs
+            .and_then(|root_fs| root_fs.layers)
+            .unwrap_or_default();
+
         Ok(ImageInspectDetails {
             id: inspect_result.id.unwrap_or_default(),
             repo_tags: inspect_result.repo_tags.unwrap_or_default(),
@@ -181,20 +571,476 @@ impl DockerClient {
             entrypoint,
             cmd,
             labels,
+            layers,
+        })
+    }
+
+    /// Full inspect response as returned by the daemon
+    pub async fn inspect_image_json(
+        &self,
+        image_id: &str,
+    ) -> Result<serde_json::Value, bollard::errors::Error> {
+        let inspect_result = self.docker.inspect_image(image_id).await?;
+        Ok(serde_json::to_value(inspect_result)?)
+    }
+
+    pub async fn inspect_container_json(
+        &self,
+        id: &str,
+    ) -> Result<serde_json::Value, bollard::errors::Error> {
+        let inspect_result = self.docker.inspect_container(id, None).await?;
+        Ok(serde_json::to_value(inspect_result)?)
+    }
+
+    pub async fn inspect_network(
+        &self,
+        name: &str,
+    ) -> Result<NetworkInspectDetails, bollard::errors::Error> {
+        let inspect_result = self.docker.inspect_network(name, None).await?;
+
+        let ipam = inspect_result.ipam.unwrap_or_default();
+        let ipam_config = ipam
+            .config
+            .unwrap_or_default()
+            .into_iter()
+            .map(|config| IpamConfigDetails {
+                subnet: config.subnet.unwrap_or_default(),
+                ip_range: config.ip_range.unwrap_or_default(),
+                gateway: config.gateway.unwrap_or_default(),
+            })
+            .collect();
+
+        // Endpoints are keyed by container ID
+        let mut containers: Vec<NetworkContainerDetails> = inspect_result
+            .containers
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(id, endpoint)| NetworkContainerDetails {
+                name: endpoint
+                    .name
+                    .unwrap_or_else(|| Self::format_container_id(&id)),
+                id,
+                ipv4_address: endpoint.ipv4_address.unwrap_or_default(),
+                ipv6_address: endpoint.ipv6_address.unwrap_or_default(),
+                mac_address: endpoint.mac_address.unwrap_or_default(),
+            })
+            .collect();
+        containers.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(NetworkInspectDetails {
+            id: inspect_result.id.unwrap_or_default(),
+            name: inspect_result.name.unwrap_or_default(),
+            driver: inspect_result.driver.unwrap_or_default(),
+            scope: inspect_result.scope.unwrap_or_default(),
+            created_formatted: match &inspect_result.created {
+                Some(created) => Self::format_datetime(created),
+                None => "Unknown".to_string(),
+            },
+            internal: inspect_result.internal.unwrap_or(false),
+            attachable: inspect_result.attachable.unwrap_or(false),
+            ingress: inspect_result.ingress.unwrap_or(false),
+            enable_ipv6: inspect_result.enable_ipv6.unwrap_or(false),
+            ipam_driver: ipam.driver.unwrap_or_else(|| "default".to_string()),
+            ipam_config,
+            options: inspect_result.options.unwrap_or_default(),
+            labels: inspect_result.labels.unwrap_or_default(),
+            containers,
+        })
+    }
+
+    pub async fn inspect_network_json(
+        &self,
+        name: &str,
+    ) -> Result<serde_json::Value, bollard::errors::Error> {
+        let inspect_result = self.docker.inspect_network(name, None).await?;
+        Ok(serde_json::to_value(inspect_result)?)
+    }
+
+    pub async fn inspect_volume_json(
+        &self,
+        name: &str,
+    ) -> Result<serde_json::Value, bollard::errors::Error> {
+        let inspect_result = self.docker.inspect_volume(name).await?;
+        Ok(serde_json::to_value(inspect_result)?)
+    }
+
+    pub async fn inspect_container(
+        &self,
+        id: &str,
+    ) -> Result<ContainerInspectDetails, bollard::errors::Error> {
+        let inspect_result = self.docker.inspect_container(id, None).await?;
+        let config = inspect_result.config.unwrap_or_default();
+        let host_config = inspect_result.host_config.unwrap_or_default();
+        let state = inspect_result.state.unwrap_or_default();
+
+        let status = state
+            .status
+            .map(|status| status.to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        let state_formatted = match state.exit_code {
+            Some(code) if status == "exited" => format!("{} ({})", status, code),
+            _ if state.oom_killed == Some(true) => format!("{} (OOM killed)", status),
+            _ => status,
+        };
+
+        // Docker reports never-set times as the zero date
+        let format_state_time = |time: Option<String>| match time {
+            Some(time) if !time.starts_with("0001-") => Self::format_datetime(&time),
+            _ => String::new(),
+        };
+
+        let (health, health_log) = match state.health {
+            Some(health) => {
+                let status = health
+                    .status
+                    .map(|status| status.to_string())
+                    .unwrap_or_default();
+                let status = match health.failing_streak {
+                    Some(streak) if streak > 0 => format!("{} ({} failing)", status, streak),
+                    _ => status,
+                };
+                let log = health
+                    .log
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|result| {
+                        format!(
+                            "[{}] {}",
+                            result.exit_code.unwrap_or_default(),
+                            result.output.unwrap_or_default().trim()
+                        )
+                    })
+                    .collect();
+                (Some(status).filter(|s| !s.is_empty() && s != "none"), log)
+            }
+            None => (None, Vec::new()),
+        };
+
+        let restart_policy = match host_config.restart_policy {
+            Some(policy) => {
+                let name = policy.name.map(|name| name.to_string()).unwrap_or_default();
+                match policy.maximum_retry_count {
+                    Some(count) if count > 0 && name == "on-failure" => {
+                        format!("{}:{}", name, count)
+                    }
+                    _ if name.is_empty() => "no".to_string(),
+                    _ => name,
+                }
+            }
+            None => "no".to_string(),
+        };
+
+        let mounts = inspect_result
+            .mounts
+            .unwrap_or_default()
+            .into_iter()
+            .map(|mount| MountDetails {
+                kind: mount.typ.unwrap_or_default(),
+                source: mount
+                    .name
+                    .filter(|name| !name.is_empty())
+                    .or(mount.source)
+                    .unwrap_or_default(),
+                destination: mount.destination.unwrap_or_default(),
+                mode: if mount.rw.unwrap_or(true) { "rw" } else { "ro" }.to_string(),
+            })
+            .collect();
+
+        let network_settings = inspect_result.network_settings.unwrap_or_default();
+        let mut networks: Vec<NetworkEndpointDetails> = network_settings
+            .networks
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, endpoint)| {
+                let with_prefix = |address: Option<String>, prefix: Option<i64>| match address {
+                    Some(address) if !address.is_empty() => match prefix {
+                        Some(prefix) if prefix > 0 => format!("{}/{}", address, prefix),
+                        _ => address,
+                    },
+                    _ => String::new(),
+                };
+                NetworkEndpointDetails {
+                    name,
+                    ip_address: with_prefix(endpoint.ip_address, endpoint.ip_prefix_len),
+                    gateway: endpoint.gateway.unwrap_or_default(),
+                    ipv6_address: with_prefix(
+                        endpoint.global_ipv6_address,
+                        endpoint.global_ipv6_prefix_len,
+                    ),
+                    mac_address: endpoint.mac_address.unwrap_or_default(),
+                    aliases: endpoint.aliases.unwrap_or_default(),
+                }
+            })
+            .collect();
+        networks.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Published ports from the running container, configured bindings otherwise
+        let port_map = network_settings
+            .ports
+            .filter(|ports| !ports.is_empty())
+            .or(host_config.port_bindings)
+            .unwrap_or_default();
+        let mut port_bindings: Vec<String> = port_map
+            .into_iter()
+            .flat_map(|(container_port, bindings)| match bindings {
+                Some(bindings) if !bindings.is_empty() => bindings
+                    .into_iter()
+                    .map(|binding| {
+                        format!(
+                            "{}:{} -> {}",
+                            binding.host_ip.unwrap_or_default(),
+                            binding.host_port.unwrap_or_default(),
+                            container_port
+                        )
+                    })
+                    .collect(),
+                _ => vec![container_port],
+            })
+            .collect();
+        port_bindings.sort();
+
+        let memory_limit = match host_config.memory {
+            Some(memory) if memory > 0 => Self::format_size(memory),
+            _ => "unlimited".to_string(),
+        };
+        let cpu_limit = match (host_config.nano_cpus, host_config.cpu_shares) {
+            (Some(nano_cpus), _) if nano_cpus > 0 => {
+                format!("{} CPUs", nano_cpus as f64 / 1_000_000_000.0)
+            }
+            (_, Some(shares)) if shares > 0 => format!("{} shares", shares),
+            _ => "unlimited".to_string(),
+        };
+        let pids_limit = match host_config.pids_limit {
+            Some(limit) if limit > 0 => limit.to_string(),
+            _ => "unlimited".to_string(),
+        };
+
+        Ok(ContainerInspectDetails {
+            id: inspect_result.id.unwrap_or_default(),
+            name: inspect_result
+                .name
+                .unwrap_or_default()
+                .trim_start_matches('/')
+                .to_string(),
+            image: config.image.or(inspect_result.image).unwrap_or_default(),
+            created_formatted: match &inspect_result.created {
+                Some(created) => Self::format_datetime(created),
+                None => "Unknown".to_string(),
+            },
+            state: state_formatted,
+            started_at: format_state_time(state.started_at),
+            finished_at: format_state_time(state.finished_at),
+            restart_policy,
+            restart_count: inspect_result.restart_count.unwrap_or(0),
+            health,
+            health_log,
+            hostname: config.hostname.unwrap_or_default(),
+            user: config.user.unwrap_or_default(),
+            working_dir: config.working_dir.unwrap_or_default(),
+            entrypoint: config.entrypoint.unwrap_or_default(),
+            cmd: config.cmd.unwrap_or_default(),
+            env: config.env.unwrap_or_default(),
+            mounts,
+            networks,
+            port_bindings,
+            memory_limit,
+            cpu_limit,
+            pids_limit,
+            labels: config.labels.unwrap_or_default(),
         })
     }
 
-    pub async fn list_networks(&self) -> Result<Vec<String>, bollard::errors::Error> {
+    /// Remove an image by ID or untag it by reference, returning what the daemon did
+    /// as "Untagged: nginx:latest" / "Deleted: sha256:..." lines
+    pub async fn remove_image(
+        &self,
+        image: &str,
+        force: bool,
+        no_prune: bool,
+    ) -> Result<Vec<String>, bollard::errors::Error> {
+        let options = RemoveImageOptionsBuilder::new()
+            .force(force)
+            .noprune(no_prune)
+            .build();
+        let items = self.docker.remove_image(image, Some(options), None).await?;
+
+        Ok(items
+            .into_iter()
+            .flat_map(|item| {
+                let untagged = item.untagged.map(|tag| format!("Untagged: {}", tag));
+                let deleted = item.deleted.map(|id| format!("Deleted: {}", id));
+                untagged.into_iter().chain(deleted)
+            })
+            .collect())
+    }
+
+    /// Layers from the newest to the base image, as `docker history`
+    pub async fn image_history(
+        &self,
+        image: &str,
+    ) -> Result<Vec<ImageLayer>, bollard::errors::Error> {
+        let history = self.docker.image_history(image).await?;
+
+        Ok(history
+            .into_iter()
+            .map(|item| ImageLayer {
+                id: item.id,
+                created_by: Self::format_created_by(&item.created_by),
+                size: item.size,
+                size_formatted: Self::format_size(item.size),
+                created_ago: Self::format_time_ago(item.created),
+                tags: item.tags,
+                comment: item.comment,
+            })
+            .collect())
+    }
+
+    /// Build from a context tarball; dropping the stream aborts the build
+    pub fn build_image(
+        &self,
+        context: impl Stream<Item = std::io::Result<Bytes>> + Send + 'static,
+        dockerfile: &str,
+        tag: Option<&str>,
+        build_args: &HashMap<String, String>,
+    ) -> impl Stream<Item = Result<BuildOutput, bollard::errors::Error>> + '_ {
+        let mut options = BuildImageOptionsBuilder::new()
+            .dockerfile(dockerfile)
+            .buildargs(build_args)
+            .rm(true);
+        if let Some(tag) = tag {
+            options = options.t(tag);
+        }
+
+        self.docker
+            .build_image(options.build(), None, Some(body_try_stream(context)))
+            .filter_map(|info| {
+                futures::future::ready(match info {
+                    Ok(info) => Self::build_output(info),
+                    Err(e) => Some(Err(e)),
+                })
+            })
+    }
+
+    /// Add `reference` ("repo:tag", tag defaults to latest) to an existing image
+    pub async fn tag_image(
+        &self,
+        image: &str,
+        reference: &str,
+    ) -> Result<(), bollard::errors::Error> {
+        let reference = Self::normalize_reference(reference);
+        let (repo, tag) = match reference.rsplit_once(':') {
+            Some((repo, tag)) if !tag.contains('/') => (repo, tag),
+            _ => (reference.as_str(), "latest"),
+        };
+        let options = TagImageOptionsBuilder::new().repo(repo).tag(tag).build();
+        self.docker.tag_image(image, Some(options)).await
+    }
+
+    pub async fn list_networks(&self) -> Result<Vec<NetworkInfo>, bollard::errors::Error> {
         let options = ListNetworksOptionsBuilder::new().build();
 
         let networks = self.docker.list_networks(Some(options)).await?;
 
+        // The list leaves endpoints out, count them from the containers
+        let options = ListContainersOptionsBuilder::new().all(true).build();
+        let containers = self.docker.list_containers(Some(options)).await?;
+        let mut attached: HashMap<String, usize> = HashMap::new();
+        for endpoint in containers
+            .into_iter()
+            .filter_map(|container| container.network_settings?.networks)
+            .flat_map(|networks| networks.into_values())
+        {
+            if let Some(network_id) = endpoint.network_id {
+                *attached.entry(network_id).or_insert(0) += 1;
+            }
+        }
+
         Ok(networks
             .into_iter()
-            .filter_map(|network| network.name)
+            .map(|network| {
+                let id = network.id.unwrap_or_default();
+                let ipam_config = network
+                    .ipam
+                    .and_then(|ipam| ipam.config)
+                    .unwrap_or_default();
+                let join = |values: Vec<String>| {
+                    if values.is_empty() {
+                        "-".to_string()
+                    } else {
+                        values.join(", ")
+                    }
+                };
+                let created = network
+                    .created
+                    .as_deref()
+                    .and_then(|created| chrono::DateTime::parse_from_rfc3339(created).ok())
+                    .map(|created| created.timestamp())
+                    .unwrap_or(0);
+
+                NetworkInfo {
+                    display_id: Self::format_container_id(&id),
+                    containers: attached.get(&id).copied().unwrap_or(0),
+                    id,
+                    name: network.name.unwrap_or_default(),
+                    driver: network.driver.unwrap_or_default(),
+                    scope: network.scope.unwrap_or_default(),
+                    subnet: join(
+                        ipam_config
+                            .iter()
+                            .filter_map(|config| config.subnet.clone())
+                            .collect(),
+                    ),
+                    gateway: join(
+                        ipam_config
+                            .iter()
+                            .filter_map(|config| config.gateway.clone())
+                            .collect(),
+                    ),
+                    internal: network.internal.unwrap_or(false),
+                    attachable: network.attachable.unwrap_or(false),
+                    created,
+                    created_ago: if created > 0 {
+                        Self::format_time_ago(created)
+                    } else {
+                        "-".to_string()
+                    },
+                }
+            })
             .collect())
     }
 
+    /// Create a network, returns its ID
+    pub async fn create_network(
+        &self,
+        config: &NetworkConfig,
+    ) -> Result<String, bollard::errors::Error> {
+        let non_empty = |value: &str| (!value.is_empty()).then(|| value.to_string());
+        let ipam = non_empty(&config.subnet).map(|subnet| Ipam {
+            config: Some(vec![IpamConfig {
+                subnet: Some(subnet),
+                gateway: non_empty(&config.gateway),
+                ip_range: non_empty(&config.ip_range),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+
+        let request = NetworkCreateRequest {
+            name: config.name.clone(),
+            driver: non_empty(&config.driver),
+            internal: Some(config.internal),
+            attachable: Some(config.attachable),
+            enable_ipv6: Some(config.enable_ipv6),
+            ipam,
+            labels: (!config.labels.is_empty()).then(|| config.labels.clone()),
+            options: (!config.options.is_empty()).then(|| config.options.clone()),
+            ..Default::default()
+        };
+
+        let response = self.docker.create_network(request).await?;
+        Ok(response.id)
+    }
+
     pub async fn list_volumes(&self) -> Result<Vec<String>, bollard::errors::Error> {
         let options = ListVolumesOptionsBuilder::new().build();
 
@@ -208,27 +1054,512 @@ impl DockerClient {
             .collect())
     }
 
-    // Additional methods for container management
-    pub async fn get_container_status(&self, name: &str) -> Result<String, bollard::errors::Error> {
-        let options = Some(ListContainersOptions {
-            all: true,
-            filters: {
-                let mut filters = HashMap::new();
-                filters.insert("name".to_string(), vec![name.to_string()]);
-                Some(filters)
-            },
+    // Container lifecycle operations, all keyed by container ID
+    pub async fn start_container(&self, id: &str) -> Result<(), bollard::errors::Error> {
+        self.docker
+            .start_container(id, None::<StartContainerOptions>)
+            .await
+    }
+
+    pub async fn stop_container(
+        &self,
+        id: &str,
+        timeout: i32,
+    ) -> Result<(), bollard::errors::Error> {
+        let options = StopContainerOptionsBuilder::new().t(timeout).build();
+        self.docker.stop_container(id, Some(options)).await
+    }
+
+    pub async fn restart_container(
+        &self,
+        id: &str,
+        timeout: i32,
+    ) -> Result<(), bollard::errors::Error> {
+        let options = RestartContainerOptionsBuilder::new().t(timeout).build();
+        self.docker.restart_container(id, Some(options)).await
+    }
+
+    pub async fn remove_container(
+        &self,
+        id: &str,
+        force: bool,
+        remove_volumes: bool,
+    ) -> Result<(), bollard::errors::Error> {
+        let options = RemoveContainerOptionsBuilder::new()
+            .force(force)
+            .v(remove_volumes)
+            .build();
+        self.docker.remove_container(id, Some(options)).await
+    }
+
+    pub async fn pause_container(&self, id: &str) -> Result<(), bollard::errors::Error> {
+        self.docker.pause_container(id).await
+    }
+
+    pub async fn unpause_container(&self, id: &str) -> Result<(), bollard::errors::Error> {
+        self.docker.unpause_container(id).await
+    }
+
+    pub async fn kill_container(
+        &self,
+        id: &str,
+        signal: &str,
+    ) -> Result<(), bollard::errors::Error> {
+        let options = KillContainerOptionsBuilder::new().signal(signal).build();
+        self.docker.kill_container(id, Some(options)).await
+    }
+
+    /// Stream container logs; `since` is a unix timestamp (0 = everything),
+    /// `tail` is a line count or "all". Frames don't follow line boundaries,
+    /// split them with a `LogDecoder`
+    pub fn stream_logs(
+        &self,
+        id: &str,
+        follow: bool,
+        since: i32,
+        tail: &str,
+    ) -> impl Stream<Item = Result<LogOutput, bollard::errors::Error>> {
+        let options = LogsOptionsBuilder::new()
+            .follow(follow)
+            .stdout(true)
+            .stderr(true)
+            .timestamps(true)
+            .since(since)
+            .tail(tail)
+            .build();
+
+        self.docker.logs(id, Some(options))
+    }
+
+    /// Create an exec instance attached to stdin/stdout/stderr, returns its ID
+    pub async fn create_exec(
+        &self,
+        id: &str,
+        cmd: Vec<String>,
+        tty: bool,
+    ) -> Result<String, bollard::errors::Error> {
+        let config = ExecConfig {
+            attach_stdin: Some(true),
+            attach_stdout: Some(true),
+            attach_stderr: Some(true),
+            tty: Some(tty),
+            cmd: Some(cmd),
             ..Default::default()
+        };
+
+        Ok(self.docker.create_exec(id, config).await?.id)
+    }
+
+    pub async fn start_exec(
+        &self,
+        exec_id: &str,
+        tty: bool,
+    ) -> Result<StartExecResults, bollard::errors::Error> {
+        let options = StartExecOptions {
+            detach: false,
+            tty,
+            output_capacity: None,
+        };
+
+        self.docker.start_exec(exec_id, Some(options)).await
+    }
+
+    pub async fn resize_exec(
+        &self,
+        exec_id: &str,
+        width: u16,
+        height: u16,
+    ) -> Result<(), bollard::errors::Error> {
+        let options = ResizeExecOptionsBuilder::new()
+            .w(width as i32)
+            .h(height as i32)
+            .build();
+
+        self.docker.resize_exec(exec_id, options).await
+    }
+
+    /// Pull progress of every layer; dropping the stream aborts the pull
+    pub fn pull_image(
+        &self,
+        reference: &str,
+        platform: Option<&str>,
+    ) -> impl Stream<Item = Result<ProgressUpdate, bollard::errors::Error>> + use<> {
+        let reference = Self::normalize_reference(reference);
+        let mut options = CreateImageOptionsBuilder::new().from_image(&reference);
+        if let Some(platform) = platform {
+            options = options.platform(platform);
+        }
+
+        self.docker
+            .create_image(Some(options.build()), None, None)
+            .map(|info| {
+                info.map(|info| Self::progress_update(info.id, info.status, info.progress_detail))
+            })
+    }
+
+    /// `docker save` of the given names into `file`, progress counts the
+    /// written bytes against `total` (the images' size, an estimate)
+    pub fn save_images(
+        &self,
+        names: &[String],
+        file: tokio::fs::File,
+        label: String,
+        total: u64,
+    ) -> impl Stream<Item = Result<ProgressUpdate, bollard::errors::Error>> + use<> {
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        let export = Box::pin(self.docker.export_images(&names));
+
+        futures::stream::unfold(Some((export, file, 0u64)), move |state| {
+            let label = label.clone();
+            async move {
+                let (mut export, mut file, mut written) = state?;
+                let update = |status: &str, current: u64| ProgressUpdate {
+                    id: label.clone(),
+                    status: status.to_string(),
+                    current,
+                    total: total.max(current),
+                };
+
+                match export.next().await {
+                    Some(Ok(bytes)) => {
+                        if let Err(e) = file.write_all(&bytes).await {
+                            return Some((Err(e.into()), None));
+                        }
+                        written += bytes.len() as u64;
+                        Some((Ok(update("Saving", written)), Some((export, file, written))))
+                    }
+                    Some(Err(e)) => Some((Err(e), None)),
+                    None => match file.sync_all().await {
+                        Ok(()) => Some((Ok(update("Saved", written)), None)),
+                        Err(e) => Some((Err(e.into()), None)),
+                    },
+                }
+            }
+        })
+    }
+
+    /// `docker load` from `file`, progress counts the bytes sent against
+    /// the file size, then the daemon reports the loaded images
+    pub fn load_images(
+        &self,
+        file: tokio::fs::File,
+        label: String,
+        total: u64,
+    ) -> impl Stream<Item = Result<ProgressUpdate, bollard::errors::Error>> + use<> {
+        // The body is consumed by the HTTP client, progress comes back on a channel
+        let (progress_tx, progress_rx) = futures::channel::mpsc::unbounded();
+        let body_label = label.clone();
+        let body = futures::stream::unfold(Some((file, 0u64)), move |state| {
+            let progress_tx = progress_tx.clone();
+            let label = body_label.clone();
+            async move {
+                let (mut file, read) = state?;
+                let mut buffer = vec![0; 1024 * 1024];
+                match file.read(&mut buffer).await {
+                    Ok(0) => None,
+                    Ok(n) => {
+                        buffer.truncate(n);
+                        let read = read + n as u64;
+                        let _ = progress_tx.unbounded_send(Ok(ProgressUpdate {
+                            id: label,
+                            status: "Loading".to_string(),
+                            current: read,
+                            total: total.max(read),
+                        }));
+                        Some((Ok(Bytes::from(buffer)), Some((file, read))))
+                    }
+                    Err(e) => Some((Err(e), None)),
+                }
+            }
         });
 
-        let containers = self.docker.list_containers(options).await?;
+        let options = ImportImageOptionsBuilder::new().quiet(false).build();
+        let output = self
+            .docker
+            .import_image_stream(options, body, None)
+            .filter_map(|info| {
+                futures::future::ready(match info {
+                    // "Loaded image: nginx:latest"
+                    Ok(info) => info
+                        .stream
+                        .map(|text| text.trim().to_string())
+                        .filter(|text| !text.is_empty())
+                        .map(|text| Ok(Self::progress_update(None, Some(text), None))),
+                    Err(e) => Some(Err(e)),
+                })
+            })
+            .chain(futures::stream::once(futures::future::ready(Ok(
+                ProgressUpdate {
+                    id: label,
+                    status: "Loaded".to_string(),
+                    current: total,
+                    total,
+                },
+            ))));
 
-        Ok(containers
-            .first()
-            .and_then(|container| container.status.clone())
-            .unwrap_or_else(|| "Unknown".to_string()))
+        futures::stream::select(progress_rx, output)
+    }
+
+    /// Push progress of every layer; dropping the stream aborts the push.
+    /// A reference without tag pushes `latest`
+    pub fn push_image(
+        &self,
+        reference: &str,
+        credentials: Option<DockerCredentials>,
+    ) -> impl Stream<Item = Result<ProgressUpdate, bollard::errors::Error>> + use<> {
+        let reference = Self::normalize_reference(reference);
+        let (repository, tag) = match reference.rsplit_once(':') {
+            Some((repository, tag)) if !tag.contains('/') => (repository, tag),
+            _ => (reference.as_str(), "latest"),
+        };
+        let push_path = format!("/images/{}/push?tag={}", repository, tag);
+
+        // bollard's `PushImageInfo` drops the layer ID of each message, so the
+        // request goes out through `create_image`, whose `CreateImageInfo` has
+        // the same shape plus the ID, with the URI swapped for the push endpoint
+        let docker = self
+            .docker
+            .clone()
+            .with_request_modifier(move |mut request| {
+                let uri = request.uri().to_string();
+                if let Some(at) = uri.find("/images/create")
+                    && let Ok(push_uri) = format!("{}{}", &uri[..at], push_path).parse()
+                {
+                    *request.uri_mut() = push_uri;
+                }
+                request
+            });
+
+        // The daemon wants the auth header on every push, even if empty
+        docker
+            .create_image(
+                None::<CreateImageOptions>,
+                None,
+                Some(credentials.unwrap_or_default()),
+            )
+            .map(|info| {
+                info.map(|info| Self::progress_update(info.id, info.status, info.progress_detail))
+            })
+    }
+
+    /// Live resource usage, one sample per second until the stream is dropped
+    pub fn stream_stats(
+        &self,
+        id: &str,
+    ) -> impl Stream<Item = Result<ContainerStats, bollard::errors::Error>> {
+        let options = StatsOptionsBuilder::new().stream(true).build();
+
+        self.docker
+            .stats(id, Some(options))
+            .map(|stats| stats.map(|stats| Self::parse_stats(&stats)))
+    }
+
+    // Helper methods for container operations
+    pub fn parse_stats(stats: &ContainerStatsResponse) -> ContainerStats {
+        let total_usage = |cpu: &Option<ContainerCpuStats>| {
+            cpu.as_ref()
+                .and_then(|cpu| cpu.cpu_usage.as_ref())
+                .and_then(|usage| usage.total_usage)
+                .unwrap_or(0)
+        };
+        let system_usage = |cpu: &Option<ContainerCpuStats>| {
+            cpu.as_ref()
+                .and_then(|cpu| cpu.system_cpu_usage)
+                .unwrap_or(0)
+        };
+
+        // Same formula as the docker CLI
+        let cpu_delta =
+            total_usage(&stats.cpu_stats).saturating_sub(total_usage(&stats.precpu_stats));
+        let system_delta =
+            system_usage(&stats.cpu_stats).saturating_sub(system_usage(&stats.precpu_stats));
+        let online_cpus = stats
+            .cpu_stats
+            .as_ref()
+            .and_then(|cpu| {
+                cpu.online_cpus.map(u64::from).or_else(|| {
+                    cpu.cpu_usage
+                        .as_ref()
+                        .and_then(|usage| usage.percpu_usage.as_ref())
+                        .map(|percpu| percpu.len() as u64)
+                })
+            })
+            .unwrap_or(1);
+        let cpu_percent = if cpu_delta > 0 && system_delta > 0 {
+            cpu_delta as f64 / system_delta as f64 * online_cpus as f64 * 100.0
+        } else {
+            0.0
+        };
+
+        // Page cache is reported as "inactive_file" on cgroup v2, "total_inactive_file" on v1
+        let (memory_usage, memory_limit) = match &stats.memory_stats {
+            Some(memory) => {
+                let usage = memory.usage.unwrap_or(0);
+                let cache = memory
+                    .stats
+                    .as_ref()
+                    .and_then(|s| {
+                        s.get("inactive_file")
+                            .or_else(|| s.get("total_inactive_file"))
+                    })
+                    .copied()
+                    .filter(|&cache| cache < usage)
+                    .unwrap_or(0);
+                (usage - cache, memory.limit.unwrap_or(0))
+            }
+            None => (0, 0),
+        };
+
+        let (net_rx, net_tx) = stats
+            .networks
+            .as_ref()
+            .map(|networks| {
+                networks.values().fold((0, 0), |(rx, tx), net| {
+                    (
+                        rx + net.rx_bytes.unwrap_or(0),
+                        tx + net.tx_bytes.unwrap_or(0),
+                    )
+                })
+            })
+            .unwrap_or((0, 0));
+
+        let (block_read, block_write) = stats
+            .blkio_stats
+            .as_ref()
+            .and_then(|blkio| blkio.io_service_bytes_recursive.as_ref())
+            .map(|entries| {
+                entries.iter().fold((0, 0), |(read, write), entry| {
+                    let value = entry.value.unwrap_or(0);
+                    match entry.op.as_deref() {
+                        Some(op) if op.eq_ignore_ascii_case("read") => (read + value, write),
+                        Some(op) if op.eq_ignore_ascii_case("write") => (read, write + value),
+                        _ => (read, write),
+                    }
+                })
+            })
+            .unwrap_or((0, 0));
+
+        ContainerStats {
+            cpu_percent,
+            memory_usage,
+            memory_limit,
+            net_rx,
+            net_tx,
+            block_read,
+            block_write,
+        }
+    }
+
+    fn parse_log_line(source: LogSource, line: &str) -> LogLine {
+        // Daemon prefixes each line with an RFC3339 timestamp
+        let (timestamp, message) = match line.split_once(' ') {
+            Some((ts, rest)) => match chrono::DateTime::parse_from_rfc3339(ts) {
+                Ok(dt) => (
+                    dt.with_timezone(&chrono::Utc)
+                        .format("%Y-%m-%d %H:%M:%S")
+                        .to_string(),
+                    rest,
+                ),
+                Err(_) => (String::new(), line),
+            },
+            None => (String::new(), line),
+        };
+
+        LogLine {
+            source,
+            timestamp,
+            message: message.trim_end_matches('\r').to_string(),
+        }
+    }
+
+    pub fn format_container_id(id: &str) -> String {
+        id.chars().take(12).collect()
+    }
+
+    pub fn format_ports(ports: &[PortSummary]) -> String {
+        let mut formatted: Vec<String> = ports
+            .iter()
+            .map(|port| {
+                let typ = port
+                    .typ
+                    .as_ref()
+                    .map(|typ| typ.to_string())
+                    .unwrap_or_else(|| "tcp".to_string());
+                match port.public_port {
+                    Some(public_port) => {
+                        format!("{}->{}/{}", public_port, port.private_port, typ)
+                    }
+                    None => format!("{}/{}", port.private_port, typ),
+                }
+            })
+            .collect();
+
+        // IPv4 and IPv6 bindings of the same port are reported separately
+        formatted.sort();
+        formatted.dedup();
+
+        if formatted.is_empty() {
+            "-".to_string()
+        } else {
+            formatted.join(", ")
+        }
     }
 
     // Helper methods for image operations
+    /// Without a tag the daemon pulls every tag of the repository
+    pub fn normalize_reference(reference: &str) -> String {
+        let name = reference.rsplit('/').next().unwrap_or(reference);
+        if name.contains(':') || name.contains('@') {
+            reference.to_string()
+        } else {
+            format!("{}:latest", reference)
+        }
+    }
+
+    /// Drop the shell wrapper the classic builder puts around instructions
+    pub fn format_created_by(created_by: &str) -> String {
+        let command = created_by.trim();
+        if let Some(instruction) = command.strip_prefix("/bin/sh -c #(nop) ") {
+            instruction.trim().to_string()
+        } else if let Some(run) = command.strip_prefix("/bin/sh -c ") {
+            format!("RUN {}", run.trim())
+        } else {
+            command.to_string()
+        }
+    }
+
+    fn build_output(info: BuildInfo) -> Option<Result<BuildOutput, bollard::errors::Error>> {
+        if let Some(error) = info.error_detail.and_then(|detail| detail.message) {
+            return Some(Err(bollard::errors::Error::DockerStreamError { error }));
+        }
+        if let Some(id) = info.aux.and_then(|aux| aux.id) {
+            return Some(Ok(BuildOutput::ImageId(id)));
+        }
+        if let Some(text) = info.stream {
+            return Some(Ok(BuildOutput::Line(text)));
+        }
+        // Base image pulls report their progress as status lines
+        let status = info.status?;
+        Some(Ok(BuildOutput::Line(match info.id {
+            Some(id) => format!("{}: {}\n", id, status),
+            None => format!("{}\n", status),
+        })))
+    }
+
+    pub fn progress_update(
+        id: Option<String>,
+        status: Option<String>,
+        detail: Option<ProgressDetail>,
+    ) -> ProgressUpdate {
+        let detail = detail.unwrap_or_default();
+        ProgressUpdate {
+            id: id.unwrap_or_default(),
+            status: status.unwrap_or_default(),
+            current: detail.current.unwrap_or(0).max(0) as u64,
+            total: detail.total.unwrap_or(0).max(0) as u64,
+        }
+    }
+
     pub fn format_image_name(image: &ImageSummary) -> String {
         if !image.repo_tags.is_empty() {
             image.repo_tags[0].clone()
@@ -266,6 +1597,26 @@ impl DockerClient {
         }
     }
 
+    /// Daemon message without the status code noise, e.g. "container is running"
+    pub fn format_error(error: &bollard::errors::Error) -> String {
+        match error {
+            bollard::errors::Error::DockerResponseServerError { message, .. } => message.clone(),
+            bollard::errors::Error::DockerStreamError { error } => error.clone(),
+            other => other.to_string(),
+        }
+    }
+
+    pub fn format_datetime(timestamp: &str) -> String {
+        use chrono::{DateTime, Utc};
+        match DateTime::parse_from_rfc3339(timestamp) {
+            Ok(dt) => dt
+                .with_timezone(&Utc)
+                .format("%Y-%m-%d %H:%M:%S UTC")
+                .to_string(),
+            Err(_) => timestamp.to_string(),
+        }
+    }
+
     pub fn format_time_ago(timestamp: i64) -> String {
         use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -299,3 +1650,73 @@ impl DockerClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncBufReadExt;
+
+    // Answers like a Docker 27 daemon (API 1.47): versioned paths above it
+    // are refused, unversioned ones use the daemon's own version
+    async fn serve_docker_27(listener: tokio::net::TcpListener) {
+        while let Ok((socket, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let (reader, mut writer) = socket.into_split();
+                let mut lines = tokio::io::BufReader::new(reader).lines();
+                while let Ok(Some(request)) = lines.next_line().await {
+                    // Skip the headers, none of these requests has a body
+                    while let Ok(Some(header)) = lines.next_line().await {
+                        if header.is_empty() {
+                            break;
+                        }
+                    }
+                    let path = request.split(' ').nth(1).unwrap_or_default().to_string();
+                    let version = path
+                        .strip_prefix("/v1.")
+                        .and_then(|rest| rest.split('/').next())
+                        .and_then(|minor| minor.parse::<u32>().ok());
+                    let (status, body) = match version {
+                        Some(minor) if minor > 47 => (
+                            "400 Bad Request",
+                            format!(
+                                r#"{{"message":"client version 1.{} is too new. Maximum supported API version is 1.47"}}"#,
+                                minor
+                            ),
+                        ),
+                        _ if path.ends_with("/version") => (
+                            "200 OK",
+                            r#"{"Version":"27.5.1","ApiVersion":"1.47","MinAPIVersion":"1.24"}"#
+                                .to_string(),
+                        ),
+                        _ => ("404 Not Found", r#"{"message":"not found"}"#.to_string()),
+                    };
+                    let response = format!(
+                        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                    if writer.write_all(response.as_bytes()).await.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    }
+
+    #[tokio::test]
+    async fn connect_negotiates_with_an_older_daemon() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(serve_docker_27(listener));
+
+        let client = DockerClient::connect(&address.to_string(), 5)
+            .await
+            .expect("connects to a Docker 27 daemon");
+        let negotiated = client.docker.client_version();
+        assert_eq!(
+            (negotiated.major_version, negotiated.minor_version),
+            (1, 47)
+        );
+    }
+}
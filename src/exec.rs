@@ -0,0 +1,129 @@
+use crate::docker::DockerClient;
+
+use bollard::exec::StartExecResults;
+use color_eyre::Result;
+use futures::StreamExt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::sync::mpsc;
+
+/// Prefer bash when the image has it, fall back to sh
+pub fn shell_command() -> Vec<String> {
+    vec![
+        "/bin/sh".to_string(),
+        "-c".to_string(),
+        "if command -v bash >/dev/null 2>&1; then exec bash; else exec sh; fi".to_string(),
+    ]
+}
+
+/// Run a TTY shell in the container on the real terminal until it exits.
+/// The caller must have left the alternate screen and stopped reading input;
+/// raw mode is expected to stay enabled so keys reach the container as-is.
+pub async fn run_interactive_shell(client: DockerClient, container_id: &str) -> Result<()> {
+    let exec_id = client
+        .create_exec(container_id, shell_command(), true)
+        .await?;
+
+    let StartExecResults::Attached {
+        mut output,
+        mut input,
+    } = client.start_exec(&exec_id, true).await?
+    else {
+        return Ok(());
+    };
+
+    let mut size = crossterm::terminal::size()?;
+    let _ = client.resize_exec(&exec_id, size.0, size.1).await;
+
+    // Stdin is read on a blocking thread that can be stopped once the shell exits
+    let stop = Arc::new(AtomicBool::new(false));
+    let (stdin_tx, mut stdin_rx) = mpsc::unbounded_channel();
+    let reader = {
+        let stop = Arc::clone(&stop);
+        tokio::task::spawn_blocking(move || read_stdin(stdin_tx, stop))
+    };
+
+    let mut stdout = tokio::io::stdout();
+    let mut resize_check = tokio::time::interval(tokio::time::Duration::from_millis(500));
+
+    let result = loop {
+        tokio::select! {
+            chunk = output.next() => match chunk {
+                Some(Ok(chunk)) => {
+                    if let Err(e) = write_flush(&mut stdout, chunk.as_ref()).await {
+                        break Err(e.into());
+                    }
+                }
+                Some(Err(e)) => break Err(e.into()),
+                None => break Ok(()),
+            },
+            Some(bytes) = stdin_rx.recv() => {
+                if let Err(e) = write_flush(&mut input, &bytes).await {
+                    break Err(e.into());
+                }
+            }
+            _ = resize_check.tick() => {
+                // Follow terminal resizes while the shell runs
+                if let Ok(new_size) = crossterm::terminal::size()
+                    && new_size != size
+                {
+                    size = new_size;
+                    let _ = client.resize_exec(&exec_id, size.0, size.1).await;
+                }
+            }
+        }
+    };
+
+    stop.store(true, Ordering::Relaxed);
+    let _ = reader.await;
+
+    result
+}
+
+async fn write_flush<W: AsyncWrite + Unpin>(writer: &mut W, bytes: &[u8]) -> std::io::Result<()> {
+    writer.write_all(bytes).await?;
+    writer.flush().await
+}
+
+#[cfg(unix)]
+fn read_stdin(stdin_tx: mpsc::UnboundedSender<Vec<u8>>, stop: Arc<AtomicBool>) {
+    let mut buf = [0u8; 1024];
+
+    while !stop.load(Ordering::Relaxed) {
+        // Poll with a timeout so a pending read never outlives the session
+        let mut fds = libc::pollfd {
+            fd: libc::STDIN_FILENO,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let ready = unsafe { libc::poll(&mut fds, 1, 100) };
+        if ready <= 0 || fds.revents & libc::POLLIN == 0 {
+            continue;
+        }
+
+        let n = unsafe { libc::read(libc::STDIN_FILENO, buf.as_mut_ptr().cast(), buf.len()) };
+        if n <= 0 || stdin_tx.send(buf[..n as usize].to_vec()).is_err() {
+            break;
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn read_stdin(stdin_tx: mpsc::UnboundedSender<Vec<u8>>, stop: Arc<AtomicBool>) {
+    use std::io::Read;
+
+    // Without poll the last read may swallow one key after the shell exits
+    let mut buf = [0u8; 1024];
+    let mut stdin = std::io::stdin();
+    while !stop.load(Ordering::Relaxed) {
+        match stdin.read(&mut buf) {
+            Ok(n) if n > 0 => {
+                if stdin_tx.send(buf[..n].to_vec()).is_err() {
+                    break;
+                }
+            }
+            _ => break,
+        }
+    }
+}
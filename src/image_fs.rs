@@ -0,0 +1,658 @@
+use crate::docker::DockerClient;
+
+use color_eyre::eyre::{Result, WrapErr, eyre};
+use flate2::read::GzDecoder;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+
+// Whiteout markers of the OCI layer format
+const WHITEOUT_PREFIX: &str = ".wh.";
+const OPAQUE_WHITEOUT: &str = ".wh..wh..opq";
+
+// Paths listed in the wasted space summary
+const WASTED_PATHS_LEN: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Added,
+    Modified,
+    Removed,
+}
+
+/// A path a layer touches. Sizes of removed directories include their content
+#[derive(Debug, Clone)]
+pub struct FileChange {
+    pub path: String,
+    pub size: u64,
+    pub is_dir: bool,
+    pub change: Change,
+}
+
+#[derive(Debug, Clone)]
+pub struct LayerFiles {
+    pub digest: String,
+    pub created_by: String,
+    pub size: u64,
+    // Sorted by path, parents first
+    pub changes: Vec<FileChange>,
+}
+
+/// A file written more than once or removed, the bytes lower layers keep for nothing
+#[derive(Debug, Clone)]
+pub struct WastedPath {
+    pub path: String,
+    // Layers writing or removing it
+    pub occurrences: usize,
+    pub wasted: u64,
+}
+
+/// Layers of an image archive as dive shows them
+#[derive(Debug, Clone)]
+pub struct ImageFilesystem {
+    pub layers: Vec<LayerFiles>,
+    pub total_size: u64,
+    pub wasted_size: u64,
+    // Biggest first
+    pub wasted_paths: Vec<WastedPath>,
+}
+
+impl ImageFilesystem {
+    /// Share of the layer bytes still visible in the final filesystem, 1.0 is perfect
+    pub fn efficiency(&self) -> f64 {
+        if self.total_size == 0 {
+            1.0
+        } else {
+            (self.total_size - self.wasted_size) as f64 / self.total_size as f64
+        }
+    }
+}
+
+// Entry of a layer tarball, before comparing with the lower layers
+enum LayerEntry {
+    File { path: String, size: u64 },
+    Dir { path: String },
+    Whiteout { path: String },
+    Opaque { dir: String },
+}
+
+// File or directory of the merged filesystem
+struct Node {
+    layer: usize,
+    size: u64,
+    is_dir: bool,
+}
+
+#[derive(Default)]
+struct Waste {
+    occurrences: usize,
+    wasted: u64,
+}
+
+/// Read an archive written by `docker save` holding a single image,
+/// legacy and OCI layouts, plain or gzipped layers
+pub fn analyze(archive: &Path) -> Result<ImageFilesystem> {
+    let open = || {
+        File::open(archive)
+            .map(|file| tar::Archive::new(BufReader::new(file)))
+            .wrap_err_with(|| format!("Cannot open {}", archive.display()))
+    };
+
+    // The manifest may come after the layers, read it first
+    let mut manifest = None;
+    for entry in open()?.entries()? {
+        let mut entry = entry?;
+        if entry.path()?.to_string_lossy() == "manifest.json" {
+            let mut content = String::new();
+            entry.read_to_string(&mut content)?;
+            manifest = Some(serde_json::from_str::<Value>(&content)?);
+            break;
+        }
+    }
+    let manifest = manifest.ok_or_else(|| eyre!("No manifest.json in the archive"))?;
+    let image = manifest
+        .get(0)
+        .ok_or_else(|| eyre!("Empty manifest.json in the archive"))?;
+    let config_path = image
+        .get("Config")
+        .and_then(Value::as_str)
+        .ok_or_else(|| eyre!("No config in manifest.json"))?
+        .to_string();
+    let layer_paths: Vec<String> = image
+        .get("Layers")
+        .and_then(Value::as_array)
+        .map(|layers| {
+            layers
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut config = None;
+    let mut scanned: HashMap<String, Vec<LayerEntry>> = HashMap::new();
+    // The legacy layout links identical layers to the first copy
+    let mut links: HashMap<String, String> = HashMap::new();
+    for entry in open()?.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.to_string_lossy().to_string();
+        if path == config_path {
+            let mut content = String::new();
+            entry.read_to_string(&mut content)?;
+            config = Some(serde_json::from_str::<Value>(&content)?);
+        } else if layer_paths.contains(&path) {
+            if entry.header().entry_type().is_symlink() {
+                if let Some(target) = entry.link_name()? {
+                    let dir = Path::new(&path).parent().unwrap_or(Path::new(""));
+                    let target = clean_path(&dir.join(target).to_string_lossy());
+                    links.insert(path, target);
+                }
+            } else {
+                let entries = scan_layer(&mut entry)
+                    .wrap_err_with(|| format!("Cannot read layer {}", path))?;
+                scanned.insert(path, entries);
+            }
+        }
+    }
+
+    // Instructions that created a layer, in order
+    let created_by: Vec<String> = config
+        .as_ref()
+        .and_then(|config| config.get("history"))
+        .and_then(Value::as_array)
+        .map(|history| {
+            history
+                .iter()
+                .filter(|step| step.get("empty_layer").and_then(Value::as_bool) != Some(true))
+                .map(|step| {
+                    let command = step.get("created_by").and_then(Value::as_str).unwrap_or("");
+                    DockerClient::format_created_by(command)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut present: BTreeMap<String, Node> = BTreeMap::new();
+    let mut waste: HashMap<String, Waste> = HashMap::new();
+    let mut layers = Vec::new();
+    let mut total_size = 0;
+    let mut wasted_size = 0;
+
+    for (index, layer_path) in layer_paths.iter().enumerate() {
+        let source = links.get(layer_path).unwrap_or(layer_path);
+        let entries = scanned
+            .get(source)
+            .ok_or_else(|| eyre!("Layer {} missing from the archive", layer_path))?;
+
+        let mut changes = Vec::new();
+        let mut layer_size = 0;
+        for entry in entries {
+            match entry {
+                LayerEntry::File { path, size } => {
+                    layer_size += size;
+                    let previous = present.insert(
+                        path.clone(),
+                        Node {
+                            layer: index,
+                            size: *size,
+                            is_dir: false,
+                        },
+                    );
+                    let change = match previous {
+                        Some(previous) if previous.layer < index => {
+                            let waste = waste.entry(path.clone()).or_default();
+                            waste.occurrences += 1;
+                            waste.wasted += previous.size;
+                            wasted_size += previous.size;
+                            Change::Modified
+                        }
+                        _ => Change::Added,
+                    };
+                    changes.push(FileChange {
+                        path: path.clone(),
+                        size: *size,
+                        is_dir: false,
+                        change,
+                    });
+                }
+                // Parents are repeated by every layer writing into them, only new ones count
+                LayerEntry::Dir { path } => {
+                    if !present.contains_key(path) {
+                        present.insert(
+                            path.clone(),
+                            Node {
+                                layer: index,
+                                size: 0,
+                                is_dir: true,
+                            },
+                        );
+                        changes.push(FileChange {
+                            path: path.clone(),
+                            size: 0,
+                            is_dir: true,
+                            change: Change::Added,
+                        });
+                    }
+                }
+                LayerEntry::Whiteout { path } => {
+                    let is_dir = present.get(path).is_some_and(|node| node.is_dir);
+                    let removed = remove_tree(&mut present, path, index, true);
+                    if removed.is_empty() {
+                        // No lower layer has it, nothing to hide
+                        continue;
+                    }
+                    let size = count_waste(&removed, &mut waste, &mut wasted_size);
+                    changes.push(FileChange {
+                        path: path.clone(),
+                        size,
+                        is_dir,
+                        change: Change::Removed,
+                    });
+                }
+                LayerEntry::Opaque { dir } => {
+                    // Only the content of lower layers is hidden
+                    let removed = remove_tree(&mut present, dir, index, false);
+                    let prefix = format!("{}/", dir);
+                    for (path, node) in &removed {
+                        let child = path.strip_prefix(&prefix).unwrap_or(path);
+                        if child.contains('/') {
+                            continue;
+                        }
+                        let nested = format!("{}/", path);
+                        let size = node.size
+                            + removed
+                                .iter()
+                                .filter(|(other, _)| other.starts_with(&nested))
+                                .map(|(_, node)| node.size)
+                                .sum::<u64>();
+                        changes.push(FileChange {
+                            path: path.clone(),
+                            size,
+                            is_dir: node.is_dir,
+                            change: Change::Removed,
+                        });
+                    }
+                    count_waste(&removed, &mut waste, &mut wasted_size);
+                }
+            }
+        }
+
+        // By component, so a directory's content follows it ("usr-x" sorts after "usr/bin")
+        changes.sort_by(|a, b| a.path.split('/').cmp(b.path.split('/')));
+        total_size += layer_size;
+        layers.push(LayerFiles {
+            digest: layer_digest(layer_path),
+            created_by: created_by.get(index).cloned().unwrap_or_default(),
+            size: layer_size,
+            changes,
+        });
+    }
+
+    let mut wasted_paths: Vec<WastedPath> = waste
+        .into_iter()
+        .filter(|(_, waste)| waste.wasted > 0)
+        .map(|(path, waste)| WastedPath {
+            path,
+            // Rewrites and removals were counted, not the first write
+            occurrences: waste.occurrences + 1,
+            wasted: waste.wasted,
+        })
+        .collect();
+    wasted_paths.sort_by(|a, b| b.wasted.cmp(&a.wasted).then_with(|| a.path.cmp(&b.path)));
+    wasted_paths.truncate(WASTED_PATHS_LEN);
+
+    Ok(ImageFilesystem {
+        layers,
+        total_size,
+        wasted_size,
+        wasted_paths,
+    })
+}
+
+fn scan_layer(reader: impl Read) -> Result<Vec<LayerEntry>> {
+    // Layers are gzipped when the image store keeps them compressed
+    let mut reader = BufReader::new(reader);
+    let is_gzip = reader.fill_buf()?.starts_with(&[0x1f, 0x8b]);
+    let reader: Box<dyn Read> = if is_gzip {
+        Box::new(GzDecoder::new(reader))
+    } else {
+        Box::new(reader)
+    };
+
+    let mut entries = Vec::new();
+    for entry in tar::Archive::new(reader).entries()? {
+        let entry = entry?;
+        let path = clean_path(&entry.path()?.to_string_lossy());
+        if path.is_empty() {
+            continue;
+        }
+
+        let (dir, name) = match path.rsplit_once('/') {
+            Some((dir, name)) => (dir, name),
+            None => ("", path.as_str()),
+        };
+        let entry = if name == OPAQUE_WHITEOUT {
+            LayerEntry::Opaque {
+                dir: dir.to_string(),
+            }
+        } else if let Some(target) = name.strip_prefix(WHITEOUT_PREFIX) {
+            LayerEntry::Whiteout {
+                path: if dir.is_empty() {
+                    target.to_string()
+                } else {
+                    format!("{}/{}", dir, target)
+                },
+            }
+        } else if entry.header().entry_type().is_dir() {
+            LayerEntry::Dir { path }
+        } else {
+            LayerEntry::File {
+                path,
+                size: entry.header().size()?,
+            }
+        };
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+// Remove `path` (when `itself`) and what's under it, written by layers below `layer`
+fn remove_tree(
+    present: &mut BTreeMap<String, Node>,
+    path: &str,
+    layer: usize,
+    itself: bool,
+) -> Vec<(String, Node)> {
+    let prefix = format!("{}/", path);
+    let mut doomed: Vec<String> = present
+        .range(prefix.clone()..)
+        .take_while(|(key, _)| key.starts_with(&prefix))
+        .filter(|(_, node)| node.layer < layer)
+        .map(|(key, _)| key.clone())
+        .collect();
+    if itself && present.get(path).is_some_and(|node| node.layer < layer) {
+        doomed.push(path.to_string());
+    }
+
+    doomed
+        .into_iter()
+        .filter_map(|key| present.remove(&key).map(|node| (key, node)))
+        .collect()
+}
+
+// Removed files are wasted entirely, returns their total size
+fn count_waste(
+    removed: &[(String, Node)],
+    waste: &mut HashMap<String, Waste>,
+    wasted_size: &mut u64,
+) -> u64 {
+    let mut size = 0;
+    for (path, node) in removed.iter().filter(|(_, node)| !node.is_dir) {
+        let entry = waste.entry(path.clone()).or_default();
+        entry.occurrences += 1;
+        entry.wasted += node.size;
+        *wasted_size += node.size;
+        size += node.size;
+    }
+    size
+}
+
+// "./usr/bin/" -> "usr/bin"
+fn clean_path(path: &str) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            part => parts.push(part),
+        }
+    }
+    parts.join("/")
+}
+
+// "blobs/sha256/<digest>" or "<id>/layer.tar" -> short ID for display
+fn layer_digest(path: &str) -> String {
+    let id = match path.strip_prefix("blobs/") {
+        Some(blob) => blob.rsplit('/').next().unwrap_or(blob),
+        None => path.split('/').next().unwrap_or(path),
+    };
+    id.chars().take(12).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::io::Write;
+
+    enum Item {
+        Dir(&'static str),
+        File(&'static str, usize),
+    }
+
+    fn layer(items: &[Item]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        for item in items {
+            let mut header = tar::Header::new_gnu();
+            let (path, size, kind) = match item {
+                Item::Dir(path) => (*path, 0, tar::EntryType::Directory),
+                Item::File(path, size) => (*path, *size, tar::EntryType::Regular),
+            };
+            header.set_entry_type(kind);
+            header.set_size(size as u64);
+            header.set_mode(0o644);
+            builder
+                .append_data(&mut header, path, vec![0u8; size].as_slice())
+                .unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    // `docker save` archive, layers given by path: either a tarball or a
+    // symlink to another layer path as the legacy layout writes
+    fn image(layers: &[(&str, Result<Vec<u8>, &str>)]) -> tempfile::NamedTempFile {
+        let mut builder = tar::Builder::new(Vec::new());
+        let append = |builder: &mut tar::Builder<Vec<u8>>, path: &str, data: &[u8]| {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            builder.append_data(&mut header, path, data).unwrap();
+        };
+
+        let paths: Vec<&str> = layers.iter().map(|(path, _)| *path).collect();
+        let manifest = json!([{ "Config": "config.json", "Layers": paths }]);
+        append(
+            &mut builder,
+            "manifest.json",
+            manifest.to_string().as_bytes(),
+        );
+        let history: Vec<Value> = (0..layers.len())
+            .map(|i| json!({ "created_by": format!("RUN step {}", i) }))
+            .collect();
+        append(
+            &mut builder,
+            "config.json",
+            json!({ "history": history }).to_string().as_bytes(),
+        );
+
+        for (path, content) in layers {
+            match content {
+                Ok(tarball) => append(&mut builder, path, tarball),
+                Err(target) => {
+                    let mut header = tar::Header::new_gnu();
+                    header.set_entry_type(tar::EntryType::Symlink);
+                    header.set_size(0);
+                    builder.append_link(&mut header, path, target).unwrap();
+                }
+            }
+        }
+
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(&builder.into_inner().unwrap()).unwrap();
+        file
+    }
+
+    fn changes(layer: &LayerFiles) -> Vec<(&str, Change, u64)> {
+        layer
+            .changes
+            .iter()
+            .map(|change| (change.path.as_str(), change.change, change.size))
+            .collect()
+    }
+
+    #[test]
+    fn overwrite_is_modified_and_wastes_the_lower_copy() {
+        let archive = image(&[
+            (
+                "blobs/sha256/aaaa",
+                Ok(layer(&[Item::Dir("etc/"), Item::File("etc/app.conf", 100)])),
+            ),
+            (
+                "blobs/sha256/bbbb",
+                Ok(layer(&[Item::Dir("etc/"), Item::File("etc/app.conf", 40)])),
+            ),
+        ]);
+        let filesystem = analyze(archive.path()).unwrap();
+
+        assert_eq!(
+            changes(&filesystem.layers[0]),
+            [
+                ("etc", Change::Added, 0),
+                ("etc/app.conf", Change::Added, 100)
+            ]
+        );
+        // The parent directory isn't reported again
+        assert_eq!(
+            changes(&filesystem.layers[1]),
+            [("etc/app.conf", Change::Modified, 40)]
+        );
+        assert_eq!(filesystem.layers[1].digest, "bbbb");
+        assert_eq!(filesystem.layers[1].created_by, "RUN step 1");
+        assert_eq!(filesystem.total_size, 140);
+        assert_eq!(filesystem.wasted_size, 100);
+        assert_eq!(filesystem.wasted_paths.len(), 1);
+        assert_eq!(filesystem.wasted_paths[0].path, "etc/app.conf");
+        assert_eq!(filesystem.wasted_paths[0].occurrences, 2);
+        assert_eq!(filesystem.wasted_paths[0].wasted, 100);
+        assert!((filesystem.efficiency() - 40.0 / 140.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn whiteout_removes_files_and_directories() {
+        let archive = image(&[
+            (
+                "blobs/sha256/aaaa",
+                Ok(layer(&[
+                    Item::Dir("tmp/"),
+                    Item::File("tmp/cache.bin", 300),
+                    Item::File("tmp/keep", 10),
+                    Item::Dir("var/lib/"),
+                    Item::File("var/lib/a", 5),
+                    Item::File("var/lib/b", 7),
+                ])),
+            ),
+            (
+                "blobs/sha256/bbbb",
+                Ok(layer(&[
+                    Item::File("tmp/.wh.cache.bin", 0),
+                    Item::File("var/.wh.lib", 0),
+                    // Nothing below to hide
+                    Item::File(".wh.missing", 0),
+                ])),
+            ),
+        ]);
+        let filesystem = analyze(archive.path()).unwrap();
+
+        assert_eq!(
+            changes(&filesystem.layers[1]),
+            [
+                ("tmp/cache.bin", Change::Removed, 300),
+                ("var/lib", Change::Removed, 12),
+            ]
+        );
+        assert!(filesystem.layers[1].changes[1].is_dir);
+        assert_eq!(filesystem.layers[1].size, 0);
+        assert_eq!(filesystem.wasted_size, 312);
+        assert_eq!(filesystem.wasted_paths[0].path, "tmp/cache.bin");
+    }
+
+    #[test]
+    fn opaque_directory_keeps_content_of_its_own_layer() {
+        let archive = image(&[
+            (
+                "blobs/sha256/aaaa",
+                Ok(layer(&[
+                    Item::Dir("data/"),
+                    Item::File("data/old", 50),
+                    Item::Dir("data/sub/"),
+                    Item::File("data/sub/x", 5),
+                ])),
+            ),
+            (
+                "blobs/sha256/bbbb",
+                Ok(layer(&[
+                    Item::Dir("data/"),
+                    // Written before the marker, still part of this layer
+                    Item::File("data/new", 20),
+                    Item::File("data/.wh..wh..opq", 0),
+                ])),
+            ),
+            (
+                "blobs/sha256/cccc",
+                Ok(layer(&[Item::File("data/new", 30)])),
+            ),
+        ]);
+        let filesystem = analyze(archive.path()).unwrap();
+
+        assert_eq!(
+            changes(&filesystem.layers[1]),
+            [
+                ("data/new", Change::Added, 20),
+                ("data/old", Change::Removed, 50),
+                ("data/sub", Change::Removed, 5),
+            ]
+        );
+        // data/new survived the opaque marker, so the next layer overwrites it
+        assert_eq!(
+            changes(&filesystem.layers[2]),
+            [("data/new", Change::Modified, 30)]
+        );
+        assert_eq!(filesystem.wasted_size, 50 + 5 + 20);
+    }
+
+    #[test]
+    fn legacy_layout_follows_symlinked_layers() {
+        let base = layer(&[Item::Dir("app/"), Item::File("app/config", 10)]);
+        let archive = image(&[
+            ("1111/layer.tar", Ok(base)),
+            ("2222/layer.tar", Ok(layer(&[Item::File("app/config", 20)]))),
+            // Same content as the first layer, stored once
+            ("3333/layer.tar", Err("../1111/layer.tar")),
+        ]);
+        let filesystem = analyze(archive.path()).unwrap();
+
+        assert_eq!(filesystem.layers.len(), 3);
+        assert_eq!(filesystem.layers[2].digest, "3333");
+        assert_eq!(
+            changes(&filesystem.layers[2]),
+            [("app/config", Change::Modified, 10)]
+        );
+        assert_eq!(filesystem.total_size, 40);
+        assert_eq!(filesystem.wasted_size, 30);
+        assert_eq!(filesystem.wasted_paths[0].occurrences, 3);
+    }
+
+    #[test]
+    fn efficiency_of_an_empty_image_is_perfect() {
+        let archive = image(&[("blobs/sha256/aaaa", Ok(layer(&[])))]);
+        let filesystem = analyze(archive.path()).unwrap();
+
+        assert_eq!(filesystem.total_size, 0);
+        assert_eq!(filesystem.efficiency(), 1.0);
+    }
+}
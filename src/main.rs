@@ -1,11 +1,27 @@
 mod app;
+mod build_context;
 mod components;
 mod docker;
+mod exec;
+mod image_fs;
+mod registry_auth;
 mod theme;
 mod ui;
+mod ui_build;
+mod ui_compare;
+mod ui_confirm;
 mod ui_containers;
+mod ui_explorer;
+mod ui_form;
+mod ui_history;
 mod ui_images;
+mod ui_input;
+mod ui_json;
+mod ui_logs;
 mod ui_networks;
+mod ui_progress;
+mod ui_stats;
+mod ui_terminal;
 mod ui_volumes;
 
 use app::App;
@@ -0,0 +1,266 @@
+use base64::Engine;
+use bollard::auth::DockerCredentials;
+use color_eyre::eyre::{Result, WrapErr, eyre};
+use serde_json::Value;
+use std::path::PathBuf;
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
+
+// Key of Docker Hub in config.json, kept from the v1 registry days
+const DOCKER_HUB_SERVER: &str = "https://index.docker.io/v1/";
+
+/// Credentials found for a registry and where they came from
+pub struct RegistryAuth {
+    pub registry: String,
+    pub credentials: Option<DockerCredentials>,
+    // "credential helper osxkeychain", "config.json" or "none", shown on auth failures
+    pub source: String,
+    // Credential helper failure that was skipped in favour of `auths`
+    pub warning: Option<String>,
+}
+
+/// Registry host of an image reference, same rule as the docker CLI:
+/// the first path component is a host only if it looks like one
+pub fn registry_host(reference: &str) -> String {
+    match reference.split_once('/') {
+        Some((first, _)) if first.contains(['.', ':']) || first == "localhost" => first.to_string(),
+        _ => "docker.io".to_string(),
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    match std::env::var_os("DOCKER_CONFIG") {
+        Some(dir) => Some(PathBuf::from(dir).join("config.json")),
+        None => std::env::var_os("HOME")
+            .map(|home| PathBuf::from(home).join(".docker").join("config.json")),
+    }
+}
+
+// "https://registry.example.com/v1/" -> "registry.example.com"
+fn normalize_server(server: &str) -> &str {
+    let server = server
+        .strip_prefix("https://")
+        .or_else(|| server.strip_prefix("http://"))
+        .unwrap_or(server);
+    server.split('/').next().unwrap_or(server)
+}
+
+fn is_docker_hub(host: &str) -> bool {
+    matches!(
+        host,
+        "docker.io" | "index.docker.io" | "registry-1.docker.io"
+    )
+}
+
+/// Look up credentials in `~/.docker/config.json` (or `$DOCKER_CONFIG`):
+/// per-registry credential helper first, then the default store, then `auths`.
+/// A failing helper doesn't stop the lookup, it's reported in `warning`
+pub async fn lookup(registry: &str) -> Result<RegistryAuth> {
+    let none = |source: &str| RegistryAuth {
+        registry: registry.to_string(),
+        credentials: None,
+        source: source.to_string(),
+        warning: None,
+    };
+
+    let Some(path) = config_path() else {
+        return Ok(none("none (no home directory)"));
+    };
+    let content = match tokio::fs::read_to_string(&path).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Ok(none("none (no docker config)"));
+        }
+        Err(e) => return Err(e).wrap_err_with(|| format!("Cannot read {}", path.display())),
+    };
+    let config: Value = serde_json::from_str(&content)
+        .wrap_err_with(|| format!("Invalid JSON in {}", path.display()))?;
+
+    // Helpers and `auths` key Docker Hub by its legacy URL
+    let server = if is_docker_hub(registry) {
+        DOCKER_HUB_SERVER
+    } else {
+        registry
+    };
+    let matches_registry = |key: &str| {
+        let host = normalize_server(key);
+        host == registry || (is_docker_hub(registry) && is_docker_hub(host))
+    };
+
+    let helper = config
+        .get("credHelpers")
+        .and_then(Value::as_object)
+        .and_then(|helpers| {
+            helpers
+                .iter()
+                .find(|(key, _)| matches_registry(key))
+                .and_then(|(_, helper)| helper.as_str())
+        })
+        .or_else(|| config.get("credsStore").and_then(Value::as_str));
+
+    let mut warning = None;
+    if let Some(helper) = helper {
+        match run_helper(helper, server).await {
+            Ok(Some(credentials)) => {
+                return Ok(RegistryAuth {
+                    registry: registry.to_string(),
+                    credentials: Some(credentials),
+                    source: format!("credential helper {}", helper),
+                    warning: None,
+                });
+            }
+            Ok(None) => {}
+            Err(e) => {
+                warning = Some(format!(
+                    "Credential helper {} failed ({:#}), falling back to config.json",
+                    helper, e
+                ));
+            }
+        }
+    }
+
+    let entry = config
+        .get("auths")
+        .and_then(Value::as_object)
+        .and_then(|auths| auths.iter().find(|(key, _)| matches_registry(key)))
+        .map(|(_, entry)| entry);
+    let Some(entry) = entry else {
+        let source = match helper {
+            Some(_) if warning.is_some() => "none (credential helper failed)",
+            Some(_) => "none (not in credential helper)",
+            None => "none (not logged in)",
+        };
+        return Ok(RegistryAuth {
+            warning,
+            ..none(source)
+        });
+    };
+
+    let field = |name: &str| {
+        entry
+            .get(name)
+            .and_then(Value::as_str)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+    let mut credentials = DockerCredentials {
+        serveraddress: Some(server.to_string()),
+        identitytoken: field("identitytoken"),
+        registrytoken: field("registrytoken"),
+        ..Default::default()
+    };
+    if let Some(auth) = field("auth") {
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(auth.trim())
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(|| eyre!("Invalid auth for {} in {}", registry, path.display()))?;
+        let (username, password) = decoded
+            .split_once(':')
+            .ok_or_else(|| eyre!("Invalid auth for {} in {}", registry, path.display()))?;
+        credentials.username = Some(username.to_string());
+        credentials.password = Some(password.to_string());
+    }
+
+    if credentials.username.is_none()
+        && credentials.identitytoken.is_none()
+        && credentials.registrytoken.is_none()
+    {
+        // `docker login` with a store leaves an empty entry behind
+        return Ok(RegistryAuth {
+            warning,
+            ..none("none (empty entry in config.json)")
+        });
+    }
+
+    Ok(RegistryAuth {
+        registry: registry.to_string(),
+        credentials: Some(credentials),
+        source: "config.json".to_string(),
+        warning,
+    })
+}
+
+/// `docker-credential-<helper> get`, None when it has nothing for the server
+async fn run_helper(helper: &str, server: &str) -> Result<Option<DockerCredentials>> {
+    let program = format!("docker-credential-{}", helper);
+    let mut child = tokio::process::Command::new(&program)
+        .arg("get")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .wrap_err_with(|| format!("Cannot run {}", program))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(server.as_bytes()).await?;
+    }
+    let output = child
+        .wait_with_output()
+        .await
+        .wrap_err_with(|| format!("{} failed", program))?;
+
+    if !output.status.success() {
+        let message = String::from_utf8_lossy(&output.stdout);
+        let message = message.trim();
+        if message.contains("credentials not found") {
+            return Ok(None);
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(eyre!(
+            "{} failed: {}",
+            program,
+            if message.is_empty() {
+                stderr.trim()
+            } else {
+                message
+            }
+        ));
+    }
+
+    let reply: Value = serde_json::from_slice(&output.stdout)
+        .wrap_err_with(|| format!("Invalid reply from {}", program))?;
+    let field = |name: &str| {
+        reply
+            .get(name)
+            .and_then(Value::as_str)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+
+    let Some(secret) = field("Secret") else {
+        return Ok(None);
+    };
+    let username = field("Username");
+    // Helpers return OAuth tokens with this placeholder user
+    let credentials = if username.as_deref() == Some("<token>") {
+        DockerCredentials {
+            identitytoken: Some(secret),
+            serveraddress: Some(server.to_string()),
+            ..Default::default()
+        }
+    } else {
+        DockerCredentials {
+            username,
+            password: Some(secret),
+            serveraddress: Some(server.to_string()),
+            ..Default::default()
+        }
+    };
+    Ok(Some(credentials))
+}
+
+/// Registry replies that mean the credentials are missing or wrong
+pub fn is_auth_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+    [
+        "unauthorized",
+        "denied",
+        "authentication required",
+        "no basic auth credentials",
+        "incorrect username or password",
+    ]
+    .iter()
+    .any(|pattern| message.contains(pattern))
+}
@@ -3,11 +3,19 @@ use crate::theme::current_theme;
 
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout},
-    style::{Color, Style},
+    layout::{Constraint, Direction, Layout, Rect},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Tabs},
 };
+use regex::Regex;
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+// Columns a tab expands to in sanitized output
+const TAB_WIDTH: usize = 4;
+
+// CSI, OSC and the remaining two-byte escape sequences
+static ANSI_REGEX: OnceLock<Regex> = OnceLock::new();
 
 pub fn draw_ui(f: &mut Frame, app: &App) {
     let theme = current_theme();
@@ -66,6 +74,92 @@ pub fn draw_ui(f: &mut Frame, app: &App) {
             "[←/→] Switch Tab   [Q/Esc/Ctrl+C] Quit"
         };
 
-    let help = Paragraph::new(help_text).style(theme.muted_style());
+    // Global status messages (e.g. exec results) replace the help line for a while
+    let help = match &app.status_message {
+        Some(message) => Paragraph::new(message.text.clone()).style(message.style()),
+        None => Paragraph::new(help_text).style(theme.muted_style()),
+    };
     f.render_widget(help, chunks[2]);
 }
+
+/// Centered popup area taking the given percentage of `area`
+pub fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let popup_area = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area)[1];
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_area)[1]
+}
+
+/// Remove terminal escape sequences, e.g. colors, from program output
+pub fn strip_ansi(text: &str) -> Cow<'_, str> {
+    ANSI_REGEX
+        .get_or_init(|| {
+            Regex::new(r"\x1b\[[0-?]*[ -/]*[@-~]|\x1b\][^\x07\x1b]*(\x07|\x1b\\)|\x1b[ -/]*[0-~]")
+                .expect("valid ANSI regex")
+        })
+        .replace_all(text, "")
+}
+
+/// Container output made safe to draw: ratatui passes escape sequences and
+/// control characters straight to the terminal, which corrupts the screen.
+/// Tabs become spaces, other control characters are dropped
+pub fn sanitize(text: &str) -> Cow<'_, str> {
+    let stripped = strip_ansi(text);
+    if !stripped.chars().any(char::is_control) {
+        return stripped;
+    }
+    let mut clean = String::with_capacity(stripped.len());
+    for c in stripped.chars() {
+        if c == '\t' {
+            let column = clean.chars().count();
+            clean.extend(std::iter::repeat_n(' ', TAB_WIDTH - column % TAB_WIDTH));
+        } else if !c.is_control() {
+            clean.push(c);
+        }
+    }
+    Cow::Owned(clean)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_ansi_removes_colors_and_titles() {
+        assert_eq!(
+            strip_ansi("\x1b[1;31merror\x1b[0m: failed"),
+            "error: failed"
+        );
+        assert_eq!(strip_ansi("\x1b[?25lhidden\x1b[?25h"), "hidden");
+        assert_eq!(strip_ansi("\x1b]0;title\x07text"), "text");
+        assert_eq!(strip_ansi("\x1b]8;;http://x\x1b\\link"), "link");
+        assert_eq!(strip_ansi("\x1b(Bplain\x1bM"), "plain");
+    }
+
+    #[test]
+    fn sanitize_drops_control_characters() {
+        assert_eq!(
+            sanitize("progress 10%\rprogress 20%"),
+            "progress 10%progress 20%"
+        );
+        assert_eq!(
+            sanitize("bell\x07 and \x1b[32mgreen\x1b[0m"),
+            "bell and green"
+        );
+        assert_eq!(sanitize("a\tbc\td"), "a   bc  d");
+        assert!(matches!(sanitize("plain text"), Cow::Borrowed(_)));
+    }
+}
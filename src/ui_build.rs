@@ -0,0 +1,528 @@
+use crate::build_context::{BuildContext, ChunkWriter};
+use crate::components::StatusMessage;
+use crate::docker::{BuildOutput, DockerClient};
+use crate::theme::current_theme;
+use crate::ui::sanitize;
+use crate::ui_input::{TextInput, expand_home};
+
+use crossterm::event::KeyCode;
+use futures::StreamExt;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+// Lines moved by [PgUp]/[PgDn]
+const PAGE_SCROLL: usize = 20;
+
+// Context chunks queued ahead of the request body
+const CONTEXT_CHUNKS: usize = 4;
+
+// Older output is dropped past this many lines
+const MAX_OUTPUT_LINES: usize = 10_000;
+
+/// What to build, as typed in the build dialog
+pub struct BuildRequest {
+    pub context_dir: PathBuf,
+    pub dockerfile: PathBuf,
+    pub tags: Vec<String>,
+    pub build_args: HashMap<String, String>,
+}
+
+pub enum BuildDialogOutcome {
+    Pending,
+    Cancelled,
+    Build(BuildRequest),
+}
+
+/// Context, Dockerfile, tags and build args of a new build, opened with [B]
+pub struct BuildDialog {
+    fields: [TextInput; 4],
+    focus: usize,
+    error: Option<String>,
+}
+
+impl BuildDialog {
+    const LABELS: [&'static str; 4] = [
+        "Context:    ",
+        "Dockerfile: ",
+        "Tags:       ",
+        "Build args: ",
+    ];
+    const HINTS: [&'static str; 4] = [
+        "Directory sent to the daemon, .dockerignore is respected",
+        "Relative to the context directory",
+        "Space separated, e.g. myapp:1.2 myapp:latest",
+        "Space separated KEY=VALUE pairs",
+    ];
+
+    pub fn new() -> Self {
+        Self {
+            fields: [
+                TextInput::with_value("."),
+                TextInput::with_value("Dockerfile"),
+                TextInput::new(),
+                TextInput::new(),
+            ],
+            focus: 0,
+            error: None,
+        }
+    }
+
+    fn request(&self) -> Result<BuildRequest, String> {
+        let context_dir = self.fields[0].value().trim();
+        if context_dir.is_empty() {
+            return Err("Context directory is required".to_string());
+        }
+        let dockerfile = self.fields[1].value().trim();
+        if dockerfile.is_empty() {
+            return Err("Dockerfile path is required".to_string());
+        }
+
+        let tags: Vec<String> = self.fields[2]
+            .value()
+            .split([' ', ','])
+            .filter(|tag| !tag.is_empty())
+            .map(str::to_string)
+            .collect();
+        if let Some(tag) = tags
+            .iter()
+            .find(|tag| tag.starts_with(['-', ':', '/']) || tag.ends_with([':', '/']))
+        {
+            return Err(format!("Invalid tag {}", tag));
+        }
+
+        let mut build_args = HashMap::new();
+        for arg in self.fields[3].value().split_whitespace() {
+            match arg.split_once('=') {
+                Some((key, value)) if !key.is_empty() => {
+                    build_args.insert(key.to_string(), value.to_string());
+                }
+                _ => return Err(format!("Build arg {} is not KEY=VALUE", arg)),
+            }
+        }
+
+        Ok(BuildRequest {
+            context_dir: expand_home(context_dir),
+            dockerfile: expand_home(dockerfile),
+            tags,
+            build_args,
+        })
+    }
+
+    pub fn handle_input(&mut self, key: KeyCode) -> BuildDialogOutcome {
+        match key {
+            KeyCode::Esc => return BuildDialogOutcome::Cancelled,
+            KeyCode::Enter => match self.request() {
+                Ok(request) => return BuildDialogOutcome::Build(request),
+                Err(e) => self.error = Some(e),
+            },
+            KeyCode::Up | KeyCode::BackTab => {
+                self.focus = (self.focus + self.fields.len() - 1) % self.fields.len();
+            }
+            KeyCode::Down | KeyCode::Tab => {
+                self.focus = (self.focus + 1) % self.fields.len();
+            }
+            key => {
+                if self.fields[self.focus].handle_input(key) {
+                    self.error = None;
+                }
+            }
+        }
+        BuildDialogOutcome::Pending
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let theme = current_theme();
+
+        let width = (area.width * 2 / 3).max(60).min(area.width);
+        let height = 10.min(area.height);
+        let popup_area = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        // Clear the background
+        f.render_widget(Clear, popup_area);
+
+        let content_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(popup_area);
+
+        let mut lines: Vec<Line> = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let style = if i == self.focus {
+                    theme.selected_style()
+                } else {
+                    theme.normal_style()
+                };
+                field.line(
+                    Span::styled(Self::LABELS[i], theme.highlight_style()),
+                    style,
+                    popup_area.width.saturating_sub(2),
+                    i == self.focus,
+                )
+            })
+            .collect();
+        lines.push(Line::from(""));
+        lines.push(Line::from(match &self.error {
+            Some(e) => Span::styled(e.clone(), theme.error_style()),
+            None => Span::styled(Self::HINTS[self.focus], theme.muted_style()),
+        }));
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .title("Build Image")
+                .borders(Borders::ALL)
+                .border_style(theme.modal_border_style()),
+        );
+        f.render_widget(paragraph, content_area[0]);
+
+        let help = Paragraph::new(self.render_help())
+            .style(theme.muted_style())
+            .alignment(Alignment::Center);
+        f.render_widget(help, content_area[1]);
+    }
+
+    pub fn render_help(&self) -> &'static str {
+        "[↑/↓/Tab] Field   [Enter] Build   [Esc] Cancel"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BuildStatus {
+    Running,
+    // ID of the built image
+    Finished(String),
+    Failed(String),
+    Cancelled,
+}
+
+enum BuildEvent {
+    Output(String),
+    Done(Result<String, String>),
+}
+
+/// Streaming output of a build running in background
+pub struct BuildView {
+    title: String,
+    lines: Vec<String>,
+    // Output not yet terminated by a newline
+    partial: String,
+    status: BuildStatus,
+    // Lines from the bottom, 0 follows the output
+    scroll_offset: usize,
+    token: CancellationToken,
+    event_rx: mpsc::UnboundedReceiver<BuildEvent>,
+}
+
+impl BuildView {
+    pub fn start(
+        request: BuildRequest,
+        client: DockerClient,
+        cancellation_token: &CancellationToken,
+    ) -> Self {
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let token = cancellation_token.child_token();
+        let task_token = token.clone();
+
+        let title = match request.tags.first() {
+            Some(tag) => format!("Build {}", tag),
+            None => format!("Build {}", request.context_dir.display()),
+        };
+
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = task_token.cancelled() => {}
+                result = Self::run(request, client, event_tx.clone()) => {
+                    let _ = event_tx.send(BuildEvent::Done(result));
+                }
+            }
+        });
+
+        Self {
+            title,
+            lines: Vec::new(),
+            partial: String::new(),
+            status: BuildStatus::Running,
+            scroll_offset: 0,
+            token,
+            event_rx,
+        }
+    }
+
+    // Dropping this future drops the build stream, which aborts the build
+    async fn run(
+        request: BuildRequest,
+        client: DockerClient,
+        event_tx: mpsc::UnboundedSender<BuildEvent>,
+    ) -> Result<String, String> {
+        let output = |text: String| {
+            let _ = event_tx.send(BuildEvent::Output(text));
+        };
+
+        output(format!(
+            "Packing context {}...\n",
+            request.context_dir.display()
+        ));
+        let context = BuildContext::new(&request.context_dir, &request.dockerfile)
+            .map_err(|e| format!("{:#}", e))?;
+        let dockerfile = context.dockerfile.clone();
+
+        // The archive is packed while it's sent, a dropped request stops the packing
+        let (chunk_tx, chunk_rx) = mpsc::channel(CONTEXT_CHUNKS);
+        let pack_tx = event_tx.clone();
+        let packing = tokio::task::spawn_blocking(move || {
+            let mut writer = ChunkWriter::new(chunk_tx.clone());
+            match context.pack(&mut writer) {
+                Ok(files) => {
+                    let _ = pack_tx.send(BuildEvent::Output(format!(
+                        "Sent {} files ({}) to the daemon\n",
+                        files,
+                        DockerClient::format_size(writer.written() as i64)
+                    )));
+                    Ok(())
+                }
+                // The build failed first, the daemon has the better error
+                Err(_) if chunk_tx.is_closed() => Ok(()),
+                Err(e) => {
+                    // Fail the request body so the daemon drops the build
+                    let message = format!("{:#}", e);
+                    let _ = chunk_tx.blocking_send(Err(std::io::Error::other(message.clone())));
+                    Err(message)
+                }
+            }
+        });
+        let body = futures::stream::unfold(chunk_rx, |mut rx| async move {
+            rx.recv().await.map(|chunk| (chunk, rx))
+        });
+
+        let mut image_id = None;
+        let mut stream = Box::pin(client.build_image(
+            body,
+            &dockerfile,
+            request.tags.first().map(String::as_str),
+            &request.build_args,
+        ));
+        while let Some(item) = stream.next().await {
+            match item {
+                Ok(BuildOutput::Line(text)) => output(text),
+                Ok(BuildOutput::ImageId(id)) => image_id = Some(id),
+                Err(e) => {
+                    drop(stream);
+                    // A packing failure explains the broken request better
+                    return match packing.await {
+                        Ok(Err(message)) => Err(message),
+                        _ => Err(DockerClient::format_error(&e)),
+                    };
+                }
+            }
+        }
+        drop(stream);
+        packing.await.map_err(|e| e.to_string())??;
+
+        let image_id =
+            image_id.ok_or_else(|| "The daemon did not report the image ID".to_string())?;
+
+        // The build API takes a single tag, the others are added afterwards
+        for tag in request.tags.iter().skip(1) {
+            client.tag_image(&image_id, tag).await.map_err(|e| {
+                format!("Failed to tag {}: {}", tag, DockerClient::format_error(&e))
+            })?;
+            output(format!("Tagged {}\n", tag));
+        }
+
+        Ok(image_id)
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.status == BuildStatus::Running
+    }
+
+    pub fn cancel(&mut self) {
+        if self.is_running() {
+            self.token.cancel();
+            self.status = BuildStatus::Cancelled;
+        }
+    }
+
+    /// ID of the image once the build succeeded
+    pub fn image_id(&self) -> Option<&str> {
+        match &self.status {
+            BuildStatus::Finished(id) => Some(id),
+            _ => None,
+        }
+    }
+
+    /// Apply received output, returns true once the build has just ended
+    pub fn poll(&mut self) -> bool {
+        let mut ended = false;
+        while let Ok(event) = self.event_rx.try_recv() {
+            match event {
+                BuildEvent::Output(text) => self.push_output(&text),
+                BuildEvent::Done(result) => {
+                    if !self.partial.is_empty() {
+                        let partial = std::mem::take(&mut self.partial);
+                        self.push_line(partial);
+                    }
+                    self.status = match result {
+                        Ok(id) => BuildStatus::Finished(id),
+                        Err(e) => BuildStatus::Failed(e),
+                    };
+                    ended = true;
+                }
+            }
+        }
+        ended
+    }
+
+    fn push_output(&mut self, text: &str) {
+        self.partial.push_str(text);
+        while let Some(end) = self.partial.find('\n') {
+            let line: String = self.partial.drain(..=end).collect();
+            self.push_line(line.trim_end_matches(['\n', '\r']).to_string());
+        }
+    }
+
+    // RUN output is drawn as text, so colors and cursor moves are dropped
+    fn push_line(&mut self, line: String) {
+        self.lines.push(sanitize(&line).into_owned());
+        if self.lines.len() > MAX_OUTPUT_LINES {
+            self.lines.remove(0);
+        }
+        // Keep the same lines on screen while scrolled back
+        if self.scroll_offset > 0 {
+            self.scroll_offset = (self.scroll_offset + 1).min(self.lines.len().saturating_sub(1));
+        }
+    }
+
+    fn current_step(&self) -> Option<&str> {
+        self.lines
+            .iter()
+            .rev()
+            .find(|line| line.starts_with("Step "))
+            .map(|line| line.split(" : ").next().unwrap_or(line))
+    }
+
+    /// One-line progress for the status bar while the view is hidden
+    pub fn summary(&self) -> StatusMessage {
+        match &self.status {
+            BuildStatus::Running => StatusMessage::info(format!(
+                "{}: {}",
+                self.title,
+                self.current_step().unwrap_or("starting")
+            )),
+            BuildStatus::Finished(_) => StatusMessage::info(format!("{}: done", self.title)),
+            BuildStatus::Failed(e) => StatusMessage::error(format!("{}: {}", self.title, e)),
+            BuildStatus::Cancelled => StatusMessage::error(format!("{}: cancelled", self.title)),
+        }
+    }
+
+    pub fn handle_input(&mut self, key: KeyCode) {
+        let max_offset = self.lines.len().saturating_sub(1);
+        match key {
+            KeyCode::Up => self.scroll_offset = (self.scroll_offset + 1).min(max_offset),
+            KeyCode::Down => self.scroll_offset = self.scroll_offset.saturating_sub(1),
+            KeyCode::PageUp => {
+                self.scroll_offset = (self.scroll_offset + PAGE_SCROLL).min(max_offset);
+            }
+            KeyCode::PageDown => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(PAGE_SCROLL);
+            }
+            KeyCode::Home => self.scroll_offset = max_offset,
+            KeyCode::End => self.scroll_offset = 0,
+            _ => {}
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let theme = current_theme();
+        let popup_area = crate::ui::centered_rect(85, 80, area);
+
+        // Clear the background
+        f.render_widget(Clear, popup_area);
+
+        let content_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(popup_area);
+
+        let (border_style, state) = match &self.status {
+            BuildStatus::Running => (
+                theme.modal_border_style(),
+                self.current_step().unwrap_or("running").to_string(),
+            ),
+            BuildStatus::Finished(_) => (theme.success_style(), "done".to_string()),
+            BuildStatus::Failed(_) => (theme.error_style(), "failed".to_string()),
+            BuildStatus::Cancelled => (theme.warning_style(), "cancelled".to_string()),
+        };
+        let block = Block::default()
+            .title(format!("{} [{}]", self.title, state))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+        let inner = block.inner(content_area[0]);
+        f.render_widget(block, content_area[0]);
+
+        let mut lines: Vec<Line> = self
+            .lines
+            .iter()
+            .map(|line| {
+                let style = if line.starts_with("Step ") {
+                    theme.highlight_style()
+                } else if line.starts_with(" ---> ") || line.starts_with("Successfully ") {
+                    theme.muted_style()
+                } else {
+                    theme.normal_style()
+                };
+                Line::from(Span::styled(line.clone(), style))
+            })
+            .collect();
+        if !self.partial.is_empty() {
+            lines.push(Line::from(Span::styled(
+                sanitize(&self.partial).into_owned(),
+                theme.normal_style(),
+            )));
+        }
+        if let BuildStatus::Failed(e) = &self.status {
+            lines.push(Line::from(Span::styled(e.clone(), theme.error_style())));
+        }
+
+        // Bottom-anchored window, moved up by the scroll offset
+        let height = inner.height as usize;
+        let end = lines.len().saturating_sub(self.scroll_offset);
+        let start = end.saturating_sub(height);
+        let visible: Vec<Line> = lines.drain(start..end).collect();
+        f.render_widget(Paragraph::new(visible), inner);
+
+        let help = Paragraph::new(self.render_help())
+            .style(theme.muted_style())
+            .alignment(Alignment::Center);
+        f.render_widget(help, content_area[1]);
+    }
+
+    pub fn render_help(&self) -> &'static str {
+        if self.is_running() {
+            "[↑/↓/PgUp/PgDn] Scroll   [End] Follow   [H] Hide   [Esc] Cancel Build"
+        } else {
+            "[↑/↓/PgUp/PgDn] Scroll   [Esc] Close"
+        }
+    }
+}
+
+impl Drop for BuildView {
+    fn drop(&mut self) {
+        self.token.cancel();
+    }
+}
@@ -0,0 +1,311 @@
+use crate::docker::ImageInspectDetails;
+use crate::theme::current_theme;
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+};
+use std::collections::BTreeSet;
+
+// Rows moved by [PgUp]/[PgDn]
+const PAGE_SCROLL: usize = 10;
+
+enum CompareRow {
+    Section(&'static str),
+    // Field, left value, right value, empty when missing on that side
+    Entry(String, String, String),
+}
+
+impl CompareRow {
+    fn differs(&self) -> bool {
+        matches!(self, CompareRow::Entry(_, left, right) if left != right)
+    }
+}
+
+/// Configuration and layers of two images side by side, differences highlighted
+pub struct CompareView {
+    left_name: String,
+    right_name: String,
+    rows: Vec<CompareRow>,
+    // Layers both images start with
+    shared_layers: usize,
+    only_differences: bool,
+    selected: usize,
+}
+
+impl CompareView {
+    pub fn new(
+        left_name: impl Into<String>,
+        left: &ImageInspectDetails,
+        right_name: impl Into<String>,
+        right: &ImageInspectDetails,
+    ) -> Self {
+        let mut rows = vec![CompareRow::Section("Image")];
+        let mut entry = |field: &str, left: String, right: String| {
+            rows.push(CompareRow::Entry(field.to_string(), left, right));
+        };
+        entry("ID", short_digest(&left.id), short_digest(&right.id));
+        entry(
+            "Platform",
+            format!("{}/{}", left.os, left.architecture),
+            format!("{}/{}", right.os, right.architecture),
+        );
+        entry(
+            "Created",
+            left.created_formatted.clone(),
+            right.created_formatted.clone(),
+        );
+        entry(
+            "Size",
+            left.size_formatted.clone(),
+            right.size_formatted.clone(),
+        );
+        entry(
+            "Working Dir",
+            left.working_dir.clone(),
+            right.working_dir.clone(),
+        );
+        entry(
+            "Entrypoint",
+            format_command(&left.entrypoint),
+            format_command(&right.entrypoint),
+        );
+        entry("Cmd", format_command(&left.cmd), format_command(&right.cmd));
+
+        // Environment compared by variable name, the value is what changes
+        rows.push(CompareRow::Section("Environment"));
+        let split_env = |env: &[String]| -> Vec<(String, String)> {
+            env.iter()
+                .map(|var| match var.split_once('=') {
+                    Some((name, value)) => (name.to_string(), value.to_string()),
+                    None => (var.clone(), String::new()),
+                })
+                .collect()
+        };
+        let (left_env, right_env) = (split_env(&left.env), split_env(&right.env));
+        let lookup = |env: &[(String, String)], name: &str| {
+            env.iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+                .unwrap_or_default()
+        };
+        // Left order first, then what only the right image sets
+        let mut names: Vec<&String> = left_env.iter().map(|(name, _)| name).collect();
+        for (name, _) in &right_env {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        for name in names {
+            rows.push(CompareRow::Entry(
+                name.clone(),
+                lookup(&left_env, name),
+                lookup(&right_env, name),
+            ));
+        }
+
+        rows.push(CompareRow::Section("Exposed Ports"));
+        let ports: BTreeSet<&String> = left
+            .exposed_ports
+            .iter()
+            .chain(&right.exposed_ports)
+            .collect();
+        for port in ports {
+            let side = |ports: &[String]| {
+                if ports.contains(port) {
+                    port.clone()
+                } else {
+                    String::new()
+                }
+            };
+            rows.push(CompareRow::Entry(
+                String::new(),
+                side(&left.exposed_ports),
+                side(&right.exposed_ports),
+            ));
+        }
+
+        rows.push(CompareRow::Section("Labels"));
+        let keys: BTreeSet<&String> = left.labels.keys().chain(right.labels.keys()).collect();
+        for key in keys {
+            rows.push(CompareRow::Entry(
+                key.clone(),
+                left.labels.get(key).cloned().unwrap_or_default(),
+                right.labels.get(key).cloned().unwrap_or_default(),
+            ));
+        }
+
+        // Compared by position, a new base changes every layer above it
+        rows.push(CompareRow::Section("Layers"));
+        let shared_layers = left
+            .layers
+            .iter()
+            .zip(&right.layers)
+            .take_while(|(left, right)| left == right)
+            .count();
+        for i in 0..left.layers.len().max(right.layers.len()) {
+            let side = |layers: &[String]| layers.get(i).map(|layer| short_digest(layer));
+            rows.push(CompareRow::Entry(
+                format!("#{}", i + 1),
+                side(&left.layers).unwrap_or_default(),
+                side(&right.layers).unwrap_or_default(),
+            ));
+        }
+
+        Self {
+            left_name: left_name.into(),
+            right_name: right_name.into(),
+            rows,
+            shared_layers,
+            only_differences: false,
+            selected: 0,
+        }
+    }
+
+    // Sections stay so the differences keep their context
+    fn visible_rows(&self) -> Vec<&CompareRow> {
+        self.rows
+            .iter()
+            .filter(|row| {
+                !self.only_differences || matches!(row, CompareRow::Section(_)) || row.differs()
+            })
+            .collect()
+    }
+
+    fn differences(&self) -> usize {
+        self.rows.iter().filter(|row| row.differs()).count()
+    }
+
+    pub fn handle_input(&mut self, key: KeyCode) {
+        let last = self.visible_rows().len().saturating_sub(1);
+        match key {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => self.selected = (self.selected + 1).min(last),
+            KeyCode::PageUp => self.selected = self.selected.saturating_sub(PAGE_SCROLL),
+            KeyCode::PageDown => self.selected = (self.selected + PAGE_SCROLL).min(last),
+            KeyCode::Home => self.selected = 0,
+            KeyCode::End => self.selected = last,
+            KeyCode::Char('d') => {
+                self.only_differences = !self.only_differences;
+                self.selected = 0;
+            }
+            _ => {}
+        }
+    }
+
+    fn row_style(row: &CompareRow) -> Style {
+        let theme = current_theme();
+        match row {
+            CompareRow::Section(_) => theme.header_style(),
+            CompareRow::Entry(_, left, right) if left == right => theme.normal_style(),
+            CompareRow::Entry(_, left, _) if left.is_empty() => theme.success_style(),
+            CompareRow::Entry(_, _, right) if right.is_empty() => theme.error_style(),
+            CompareRow::Entry(..) => theme.warning_style(),
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let theme = current_theme();
+        let popup_area = crate::ui::centered_rect(95, 85, area);
+
+        // Clear the background
+        f.render_widget(Clear, popup_area);
+
+        let content_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(popup_area);
+
+        let rows: Vec<Row> = self
+            .visible_rows()
+            .into_iter()
+            .map(|row| {
+                let cells = match row {
+                    CompareRow::Section(title) => vec![Cell::from(*title)],
+                    CompareRow::Entry(field, left, right) => {
+                        let marker = if row.differs() { "≠ " } else { "  " };
+                        vec![
+                            Cell::from(format!("{}{}", marker, field)),
+                            Cell::from(left.clone()),
+                            Cell::from(right.clone()),
+                        ]
+                    }
+                };
+                Row::new(cells).style(Self::row_style(row))
+            })
+            .collect();
+
+        let headers = Row::new(vec![
+            Cell::from("Field").style(theme.header_style()),
+            Cell::from(self.left_name.clone()).style(theme.header_style()),
+            Cell::from(self.right_name.clone()).style(theme.header_style()),
+        ]);
+
+        let filter = if self.only_differences {
+            ", differences only"
+        } else {
+            ""
+        };
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(20),
+                Constraint::Percentage(40),
+                Constraint::Percentage(40),
+            ],
+        )
+        .header(headers)
+        .row_highlight_style(theme.selected_style())
+        .block(
+            Block::default()
+                .title(format!(
+                    "Compare ({} differences, {} shared base layers{})",
+                    self.differences(),
+                    self.shared_layers,
+                    filter
+                ))
+                .borders(Borders::ALL)
+                .border_style(theme.modal_border_style()),
+        )
+        .column_spacing(1);
+
+        let mut state = TableState::default().with_selected(Some(self.selected));
+        f.render_stateful_widget(table, content_area[0], &mut state);
+
+        let help = Paragraph::new(self.render_help())
+            .style(theme.muted_style())
+            .alignment(Alignment::Center);
+        f.render_widget(help, content_area[1]);
+    }
+
+    pub fn render_help(&self) -> &'static str {
+        if self.only_differences {
+            "[↑/↓/PgUp/PgDn] Scroll   [D] Show All   [Esc] Close"
+        } else {
+            "[↑/↓/PgUp/PgDn] Scroll   [D] Differences Only   [Esc] Close"
+        }
+    }
+}
+
+// Arguments joined as the shell would read them back
+fn format_command(args: &[String]) -> String {
+    args.iter()
+        .map(|arg| {
+            if arg.is_empty() || arg.contains(char::is_whitespace) {
+                format!("\"{}\"", arg)
+            } else {
+                arg.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// "sha256:0123456789abcdef..." -> "0123456789ab"
+fn short_digest(digest: &str) -> String {
+    let digest = digest.strip_prefix("sha256:").unwrap_or(digest);
+    digest.chars().take(12).collect()
+}
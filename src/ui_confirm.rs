@@ -0,0 +1,188 @@
+use crate::theme::current_theme;
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmOutcome {
+    Pending,
+    Confirmed,
+    Cancelled,
+}
+
+struct ConfirmOption {
+    key: String,
+    label: String,
+    checked: bool,
+}
+
+/// Yes/no dialog for destructive actions, with details about the target
+/// and optional checkboxes that the caller reads back on confirmation
+pub struct ConfirmDialog {
+    title: String,
+    confirm_label: String,
+    details: Vec<(String, String)>,
+    notes: Vec<String>,
+    options: Vec<ConfirmOption>,
+    focused: usize,
+}
+
+impl ConfirmDialog {
+    pub fn new(title: impl Into<String>, confirm_label: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            confirm_label: confirm_label.into(),
+            details: Vec::new(),
+            notes: Vec::new(),
+            options: Vec::new(),
+            focused: 0,
+        }
+    }
+
+    /// "Label: value" line describing the target
+    pub fn detail(mut self, label: impl Into<String>, value: impl Into<String>) -> Self {
+        self.details.push((label.into(), value.into()));
+        self
+    }
+
+    /// Free text shown under the details, e.g. a warning
+    pub fn note(mut self, text: impl Into<String>) -> Self {
+        self.notes.push(text.into());
+        self
+    }
+
+    pub fn option(
+        mut self,
+        key: impl Into<String>,
+        label: impl Into<String>,
+        checked: bool,
+    ) -> Self {
+        self.options.push(ConfirmOption {
+            key: key.into(),
+            label: label.into(),
+            checked,
+        });
+        self
+    }
+
+    pub fn is_checked(&self, key: &str) -> bool {
+        self.options
+            .iter()
+            .any(|option| option.key == key && option.checked)
+    }
+
+    /// Keys of all checked options, in the order they were added
+    pub fn checked(&self) -> Vec<&str> {
+        self.options
+            .iter()
+            .filter(|option| option.checked)
+            .map(|option| option.key.as_str())
+            .collect()
+    }
+
+    pub fn handle_input(&mut self, key: KeyCode) -> ConfirmOutcome {
+        match key {
+            KeyCode::Esc | KeyCode::Char('n') => return ConfirmOutcome::Cancelled,
+            KeyCode::Enter | KeyCode::Char('y') => return ConfirmOutcome::Confirmed,
+            KeyCode::Up | KeyCode::BackTab => {
+                self.focused = self.focused.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Tab => {
+                self.focused = (self.focused + 1).min(self.options.len().saturating_sub(1));
+            }
+            KeyCode::Char(' ') => {
+                if let Some(option) = self.options.get_mut(self.focused) {
+                    option.checked = !option.checked;
+                }
+            }
+            _ => {}
+        }
+        ConfirmOutcome::Pending
+    }
+
+    pub fn render_help(&self) -> &'static str {
+        if self.options.is_empty() {
+            "[Enter/Y] Confirm   [Esc/N] Cancel"
+        } else {
+            "[↑/↓] Select Option   [Space] Toggle   [Enter/Y] Confirm   [Esc/N] Cancel"
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let theme = current_theme();
+
+        let mut lines: Vec<Line> = self
+            .details
+            .iter()
+            .map(|(label, value)| {
+                Line::from(vec![
+                    Span::styled(format!("{}: ", label), theme.highlight_style()),
+                    Span::styled(value.clone(), theme.normal_style()),
+                ])
+            })
+            .collect();
+
+        if !self.notes.is_empty() {
+            lines.push(Line::from(""));
+            lines.extend(
+                self.notes
+                    .iter()
+                    .map(|note| Line::from(Span::styled(note.clone(), theme.warning_style()))),
+            );
+        }
+
+        if !self.options.is_empty() {
+            lines.push(Line::from(""));
+            for (i, option) in self.options.iter().enumerate() {
+                let style = if i == self.focused {
+                    theme.selected_style()
+                } else {
+                    theme.normal_style()
+                };
+                let checkbox = if option.checked { "[x]" } else { "[ ]" };
+                lines.push(Line::from(Span::styled(
+                    format!("{} {}", checkbox, option.label),
+                    style,
+                )));
+            }
+        }
+
+        // Size the popup to its content, borders and help line included
+        let height = (lines.len() as u16 + 3).min(area.height);
+        let width = (area.width / 2).max(50).min(area.width);
+        let popup_area = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        // Clear the background
+        f.render_widget(Clear, popup_area);
+
+        let content_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(popup_area);
+
+        let paragraph = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(self.title.clone())
+                    .borders(Borders::ALL)
+                    .border_style(theme.modal_border_style()),
+            )
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, content_area[0]);
+
+        let help = Paragraph::new(format!("[Enter] {}   [Esc] Cancel", self.confirm_label))
+            .style(theme.muted_style())
+            .alignment(Alignment::Center);
+        f.render_widget(help, content_area[1]);
+    }
+}
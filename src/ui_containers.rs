@@ -1,34 +1,161 @@
-use crate::components::Component;
-use crate::docker::DockerClient;
+use crate::app::AppEvent;
+use crate::components::{Component, StatusMessage};
+use crate::docker::{ContainerInfo, ContainerInspectDetails, ContainerStats, DockerClient};
 use crate::theme::current_theme;
+use crate::ui_confirm::{ConfirmDialog, ConfirmOutcome};
+use crate::ui_json::JsonView;
+use crate::ui_logs::LogViewer;
+use crate::ui_stats::StatsMonitor;
+use crate::ui_terminal::TerminalPane;
 use color_eyre::Result;
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     Frame,
-    style::{Color, Style},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, List, ListItem, Paragraph, Row, Table},
 };
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, Notify, mpsc};
+use tokio_util::sync::CancellationToken;
 
 use async_trait::async_trait;
 
+// Seconds the daemon waits before killing the container on stop/restart
+const STOP_TIMEOUT_SECS: i32 = 10;
+
+// Signals offered by the kill dialog
+const KILL_SIGNALS: &[&str] = &[
+    "SIGKILL", "SIGTERM", "SIGINT", "SIGHUP", "SIGQUIT", "SIGUSR1", "SIGUSR2",
+];
+
+// Collapsible sections of the inspect modal, in display order
+const INSPECT_SECTIONS: &[&str] = &[
+    "Overview",
+    "State & Health",
+    "Config",
+    "Environment",
+    "Mounts",
+    "Networks",
+    "Port Bindings",
+    "Resource Limits",
+    "Labels",
+];
+
+#[derive(Debug, Clone)]
+enum ContainerAction {
+    Start,
+    Stop,
+    Restart,
+    Pause,
+    Unpause,
+    Kill(String),
+    Remove { force: bool, volumes: bool },
+}
+
+impl ContainerAction {
+    fn verb(&self) -> &'static str {
+        match self {
+            Self::Start => "start",
+            Self::Stop => "stop",
+            Self::Restart => "restart",
+            Self::Pause => "pause",
+            Self::Unpause => "unpause",
+            Self::Kill(_) => "kill",
+            Self::Remove { .. } => "remove",
+        }
+    }
+
+    fn past_tense(&self) -> &'static str {
+        match self {
+            Self::Start => "started",
+            Self::Stop => "stopped",
+            Self::Restart => "restarted",
+            Self::Pause => "paused",
+            Self::Unpause => "unpaused",
+            Self::Kill(_) => "killed",
+            Self::Remove { .. } => "removed",
+        }
+    }
+}
+
 pub struct ContainersUI {
     tab_num: usize,
     docker_client: Arc<Mutex<DockerClient>>,
     selected_index: usize,
-    containers: Vec<String>,
+    containers: Vec<ContainerInfo>,
     last_tick: std::time::Instant,
+    // Results of lifecycle actions running in background
+    action_tx: mpsc::UnboundedSender<StatusMessage>,
+    action_rx: mpsc::UnboundedReceiver<StatusMessage>,
+    status_message: Option<StatusMessage>,
+    // Kill dialog and the container it applies to, captured when it opens
+    // so a background refresh can't retarget the signal
+    kill_target: Option<ContainerInfo>,
+    kill_signal_index: usize,
+    // Removal confirmation and the container it applies to
+    remove_dialog: Option<(ConfirmDialog, ContainerInfo)>,
+    // Full-screen log viewer, streams in background while open
+    log_viewer: Option<LogViewer>,
+    cancellation_token: CancellationToken,
+    // App-level requests (exec suspends the whole TUI)
+    event_tx: mpsc::UnboundedSender<AppEvent>,
+    // Wakes the main loop to draw output of the shell pane
+    redraw: Arc<Notify>,
+    // Embedded shell shown next to the table or the logs
+    terminal_pane: Option<TerminalPane>,
+    pane_focused: bool,
+    // Live resource usage of running containers
+    stats: StatsMonitor,
+    // Container whose stats history is shown full-tab
+    stats_container: Option<String>,
+    // Inspect modal state
+    show_inspect_modal: bool,
+    inspect_data: Option<ContainerInspectDetails>,
+    inspect_scroll: usize,
+    inspect_section: usize,
+    collapsed_sections: Vec<bool>,
+    // Raw JSON view of the inspected container, replaces the sections when open
+    json_view: Option<JsonView>,
 }
 
 impl ContainersUI {
-    pub fn new(docker_client: Arc<Mutex<DockerClient>>, tab_num: usize) -> Self {
+    pub fn new(
+        docker_client: Arc<Mutex<DockerClient>>,
+        tab_num: usize,
+        cancellation_token: CancellationToken,
+        event_tx: mpsc::UnboundedSender<AppEvent>,
+        redraw: Arc<Notify>,
+    ) -> Self {
+        let (action_tx, action_rx) = mpsc::unbounded_channel();
+        let stats = StatsMonitor::new(cancellation_token.clone());
         Self {
             tab_num,
             docker_client,
             selected_index: 0,
             containers: Vec::new(),
             last_tick: std::time::Instant::now(),
+            action_tx,
+            action_rx,
+            status_message: None,
+            kill_target: None,
+            kill_signal_index: 0,
+            remove_dialog: None,
+            log_viewer: None,
+            cancellation_token,
+            event_tx,
+            redraw,
+            terminal_pane: None,
+            pane_focused: false,
+            stats,
+            stats_container: None,
+            show_inspect_modal: false,
+            inspect_data: None,
+            inspect_scroll: 0,
+            inspect_section: 0,
+            collapsed_sections: vec![false; INSPECT_SECTIONS.len()],
+            json_view: None,
         }
     }
 
@@ -37,6 +164,7 @@ impl ContainersUI {
         match client.list_containers().await {
             Ok(containers) => {
                 self.containers = containers;
+                self.stats.sync(&client, &self.containers);
                 // Adjust selected index if necessary
                 if self.selected_index >= self.containers.len() && !self.containers.is_empty() {
                     self.selected_index = self.containers.len() - 1;
@@ -50,46 +178,642 @@ impl ContainersUI {
         }
     }
 
-    fn get_selected_container(&self) -> Option<&String> {
+    fn get_selected_container(&self) -> Option<&ContainerInfo> {
         self.containers.get(self.selected_index)
     }
 
-    async fn toggle_container_state(&self, container_name: &str) -> Result<()> {
-        let client = self.docker_client.lock().await;
+    fn state_style(state: &str) -> Style {
+        let theme = current_theme();
+        match state {
+            "running" => theme.running_status_style(),
+            "exited" | "dead" => theme.stopped_status_style(),
+            "paused" => theme.warning_style(),
+            "restarting" | "removing" => theme.loading_style(),
+            _ => theme.muted_style(),
+        }
+    }
 
-        // Get current status and toggle
-        match client.get_container_status(container_name).await {
-            Ok(status) => {
-                if status.contains("Up") {
-                    // Container is running, stop it
-                    eprintln!("Stopping container: {}", container_name);
-                    // TODO: Implement stop_container in DockerClient
-                } else {
-                    // Container is stopped, start it
-                    eprintln!("Starting container: {}", container_name);
-                    // TODO: Implement start_container in DockerClient
+    async fn run_action(&mut self, container: ContainerInfo, action: ContainerAction) {
+        self.status_message = Some(StatusMessage::info(format!(
+            "Requested {} of {}...",
+            action.verb(),
+            container.name
+        )));
+
+        // Work on a clone so a slow stop doesn't hold the shared client lock
+        let client = self.docker_client.lock().await.clone();
+        let action_tx = self.action_tx.clone();
+
+        tokio::spawn(async move {
+            let result = match &action {
+                ContainerAction::Start => client.start_container(&container.id).await,
+                ContainerAction::Stop => {
+                    client
+                        .stop_container(&container.id, STOP_TIMEOUT_SECS)
+                        .await
+                }
+                ContainerAction::Restart => {
+                    client
+                        .restart_container(&container.id, STOP_TIMEOUT_SECS)
+                        .await
                 }
+                ContainerAction::Pause => client.pause_container(&container.id).await,
+                ContainerAction::Unpause => client.unpause_container(&container.id).await,
+                ContainerAction::Kill(signal) => client.kill_container(&container.id, signal).await,
+                ContainerAction::Remove { force, volumes } => {
+                    client
+                        .remove_container(&container.id, *force, *volumes)
+                        .await
+                }
+            };
+
+            let message = match result {
+                Ok(()) => StatusMessage::info(format!(
+                    "Container {} {}",
+                    container.name,
+                    action.past_tense()
+                )),
+                Err(e) => StatusMessage::error(format!(
+                    "Failed to {} {}: {}",
+                    action.verb(),
+                    container.name,
+                    DockerClient::format_error(&e)
+                )),
+            };
+            let _ = action_tx.send(message);
+        });
+    }
+
+    async fn toggle_container_state(&mut self, container: ContainerInfo) {
+        let action = match container.state.as_str() {
+            "running" | "paused" | "restarting" => ContainerAction::Stop,
+            _ => ContainerAction::Start,
+        };
+        self.run_action(container, action).await;
+    }
+
+    async fn toggle_container_pause(&mut self, container: ContainerInfo) {
+        let action = if container.state == "paused" {
+            ContainerAction::Unpause
+        } else {
+            ContainerAction::Pause
+        };
+        self.run_action(container, action).await;
+    }
+
+    async fn show_container_logs(&mut self, container: &ContainerInfo) {
+        let client = self.docker_client.lock().await.clone();
+        self.log_viewer = Some(LogViewer::open(client, container, &self.cancellation_token));
+    }
+
+    fn exec_shell(&mut self, container: &ContainerInfo) {
+        if container.state != "running" {
+            self.status_message = Some(StatusMessage::error(format!(
+                "Container {} is not running",
+                container.name
+            )));
+            return;
+        }
+
+        let _ = self.event_tx.send(AppEvent::ExecShell {
+            container_id: container.id.clone(),
+            container_name: container.name.clone(),
+        });
+    }
+
+    /// [F2]: open the embedded shell, focus it, or close it once exited.
+    /// [F3] closes it while running
+    async fn toggle_terminal_pane(&mut self) {
+        if let Some(pane) = &self.terminal_pane {
+            if pane.is_exited() {
+                self.terminal_pane = None;
+                self.pane_focused = false;
+            } else {
+                self.pane_focused = true;
+            }
+            return;
+        }
+
+        let Some(container) = self.get_selected_container().cloned() else {
+            return;
+        };
+        if container.state != "running" {
+            self.status_message = Some(StatusMessage::error(format!(
+                "Container {} is not running",
+                container.name
+            )));
+            return;
+        }
+
+        let client = self.docker_client.lock().await.clone();
+        self.terminal_pane = Some(TerminalPane::open(
+            client,
+            &container,
+            &self.cancellation_token,
+            Arc::clone(&self.redraw),
+        ));
+        self.pane_focused = true;
+    }
+
+    // Dropping the pane cancels the exec session
+    fn close_terminal_pane(&mut self) {
+        self.terminal_pane = None;
+        self.pane_focused = false;
+    }
+
+    async fn inspect_container(&mut self, container: &ContainerInfo) {
+        // Show modal immediately with loading state
+        self.show_inspect_modal = true;
+        self.inspect_data = None;
+        self.inspect_scroll = 0;
+        self.inspect_section = 0;
+        self.collapsed_sections = vec![false; INSPECT_SECTIONS.len()];
+        self.json_view = None;
+
+        let client = self.docker_client.lock().await;
+        match client.inspect_container(&container.id).await {
+            Ok(details) => {
+                self.inspect_data = Some(details);
+            }
+            Err(e) => {
+                self.show_inspect_modal = false;
+                self.status_message = Some(StatusMessage::error(format!(
+                    "Failed to inspect {}: {}",
+                    container.name, e
+                )));
+            }
+        }
+    }
+
+    async fn toggle_json_view(&mut self) {
+        if self.json_view.take().is_some() {
+            return;
+        }
+        let Some(data) = &self.inspect_data else {
+            return;
+        };
+
+        let client = self.docker_client.lock().await;
+        match client.inspect_container_json(&data.id).await {
+            Ok(value) => {
+                self.json_view = Some(JsonView::new(
+                    format!("Container Inspection: {} (raw JSON)", data.name),
+                    value,
+                ));
             }
             Err(e) => {
-                eprintln!("Failed to get container status: {}", e);
+                self.status_message = Some(StatusMessage::error(format!(
+                    "Failed to inspect {}: {}",
+                    data.name, e
+                )));
+            }
+        }
+    }
+
+    fn inspect_field<'a>(label: &str, value: impl Into<String>) -> Line<'a> {
+        let theme = current_theme();
+        let value = value.into();
+        Line::from(vec![
+            Span::styled(format!("  {}: ", label), theme.highlight_style()),
+            if value.is_empty() {
+                Span::styled("-", theme.muted_style())
+            } else {
+                Span::styled(value, theme.normal_style())
+            },
+        ])
+    }
+
+    fn inspect_item<'a>(value: impl Into<String>) -> Line<'a> {
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled(value.into(), current_theme().info_style()),
+        ])
+    }
+
+    fn inspect_section_lines<'a>(data: &ContainerInspectDetails, section: usize) -> Vec<Line<'a>> {
+        let mut lines = match section {
+            0 => vec![
+                Self::inspect_field("ID", data.id.clone()),
+                Self::inspect_field("Name", data.name.clone()),
+                Self::inspect_field("Image", data.image.clone()),
+                Self::inspect_field("Created", data.created_formatted.clone()),
+            ],
+            1 => {
+                let mut lines = vec![
+                    Self::inspect_field("State", data.state.clone()),
+                    Self::inspect_field("Started", data.started_at.clone()),
+                    Self::inspect_field("Finished", data.finished_at.clone()),
+                    Self::inspect_field("Restart Policy", data.restart_policy.clone()),
+                    Self::inspect_field("Restart Count", data.restart_count.to_string()),
+                    Self::inspect_field(
+                        "Health",
+                        data.health
+                            .clone()
+                            .unwrap_or_else(|| "no healthcheck".to_string()),
+                    ),
+                ];
+                lines.extend(
+                    data.health_log
+                        .iter()
+                        .map(|entry| Self::inspect_item(format!("  {}", entry))),
+                );
+                lines
             }
+            2 => vec![
+                Self::inspect_field("Hostname", data.hostname.clone()),
+                Self::inspect_field("User", data.user.clone()),
+                Self::inspect_field("Working Directory", data.working_dir.clone()),
+                Self::inspect_field("Entrypoint", data.entrypoint.join(" ")),
+                Self::inspect_field("Command", data.cmd.join(" ")),
+            ],
+            3 => data
+                .env
+                .iter()
+                .map(|env| Self::inspect_item(env.clone()))
+                .collect(),
+            4 => data
+                .mounts
+                .iter()
+                .map(|mount| {
+                    Self::inspect_item(format!(
+                        "{} {} -> {} ({})",
+                        mount.kind, mount.source, mount.destination, mount.mode
+                    ))
+                })
+                .collect(),
+            5 => data
+                .networks
+                .iter()
+                .flat_map(|network| {
+                    let mut lines = vec![
+                        Line::from(Span::styled(
+                            format!("  {}", network.name),
+                            current_theme().info_style(),
+                        )),
+                        Self::inspect_field("  IP Address", network.ip_address.clone()),
+                        Self::inspect_field("  Gateway", network.gateway.clone()),
+                        Self::inspect_field("  MAC Address", network.mac_address.clone()),
+                    ];
+                    if !network.ipv6_address.is_empty() {
+                        lines.push(Self::inspect_field(
+                            "  IPv6 Address",
+                            network.ipv6_address.clone(),
+                        ));
+                    }
+                    if !network.aliases.is_empty() {
+                        lines.push(Self::inspect_field("  Aliases", network.aliases.join(", ")));
+                    }
+                    lines
+                })
+                .collect(),
+            6 => data
+                .port_bindings
+                .iter()
+                .map(|binding| Self::inspect_item(binding.clone()))
+                .collect(),
+            7 => vec![
+                Self::inspect_field("Memory", data.memory_limit.clone()),
+                Self::inspect_field("CPU", data.cpu_limit.clone()),
+                Self::inspect_field("PIDs", data.pids_limit.clone()),
+            ],
+            _ => {
+                let mut labels: Vec<_> = data.labels.iter().collect();
+                labels.sort();
+                labels
+                    .into_iter()
+                    .map(|(key, value)| Self::inspect_field(key, value.clone()))
+                    .collect()
+            }
+        };
+
+        if lines.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  (none)",
+                current_theme().muted_style(),
+            )));
+        }
+        lines
+    }
+
+    /// All modal lines plus the row of each section header
+    fn inspect_lines<'a>(&self, data: &ContainerInspectDetails) -> (Vec<Line<'a>>, Vec<usize>) {
+        let theme = current_theme();
+        let mut lines = Vec::new();
+        let mut headers = Vec::new();
+
+        for (i, title) in INSPECT_SECTIONS.iter().enumerate() {
+            let collapsed = self.collapsed_sections[i];
+            let style = if i == self.inspect_section {
+                theme.selected_style()
+            } else {
+                theme.header_style()
+            };
+
+            headers.push(lines.len());
+            lines.push(Line::from(Span::styled(
+                format!("{} {}", if collapsed { "▸" } else { "▾" }, title),
+                style,
+            )));
+            if !collapsed {
+                lines.extend(Self::inspect_section_lines(data, i));
+                lines.push(Line::from(""));
+            }
+        }
+
+        (lines, headers)
+    }
+
+    fn handle_inspect_input(&mut self, key: KeyCode) {
+        let Some(data) = &self.inspect_data else {
+            if key == KeyCode::Esc {
+                self.show_inspect_modal = false;
+            }
+            return;
+        };
+        let (lines, _) = self.inspect_lines(data);
+        let max_scroll = lines.len().saturating_sub(1);
+
+        let mut jump_to_section = false;
+        match key {
+            KeyCode::Esc => {
+                self.show_inspect_modal = false;
+                self.inspect_data = None;
+                self.inspect_scroll = 0;
+                self.json_view = None;
+            }
+            KeyCode::Up => {
+                self.inspect_scroll = self.inspect_scroll.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                self.inspect_scroll = (self.inspect_scroll + 1).min(max_scroll);
+            }
+            KeyCode::PageUp => {
+                self.inspect_scroll = self.inspect_scroll.saturating_sub(10);
+            }
+            KeyCode::PageDown => {
+                self.inspect_scroll = (self.inspect_scroll + 10).min(max_scroll);
+            }
+            KeyCode::Tab => {
+                self.inspect_section = (self.inspect_section + 1) % INSPECT_SECTIONS.len();
+                jump_to_section = true;
+            }
+            KeyCode::BackTab => {
+                self.inspect_section =
+                    (self.inspect_section + INSPECT_SECTIONS.len() - 1) % INSPECT_SECTIONS.len();
+                jump_to_section = true;
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                let collapsed = &mut self.collapsed_sections[self.inspect_section];
+                *collapsed = !*collapsed;
+                jump_to_section = true;
+            }
+            KeyCode::Char('a') => {
+                // Collapse everything unless it's all collapsed already
+                let collapse = self.collapsed_sections.iter().any(|collapsed| !collapsed);
+                self.collapsed_sections.fill(collapse);
+                jump_to_section = true;
+            }
+            _ => {}
         }
 
-        Ok(())
+        if jump_to_section && let Some(data) = &self.inspect_data {
+            let (_, headers) = self.inspect_lines(data);
+            self.inspect_scroll = headers[self.inspect_section];
+        }
     }
 
-    async fn show_container_logs(&self, container_name: &str) -> Result<()> {
-        eprintln!("Showing logs for container: {}", container_name);
-        // TODO: Implement logs functionality
-        // This could open a popup or new view with logs
-        Ok(())
+    fn render_inspect_modal(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let theme = current_theme();
+        let popup_area = crate::ui::centered_rect(80, 80, area);
+
+        // Clear the background
+        f.render_widget(Clear, popup_area);
+
+        if let Some(json_view) = &self.json_view {
+            let content_area = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(1)])
+                .split(popup_area);
+
+            json_view.render(f, content_area[0]);
+
+            let help = Paragraph::new(
+                "[↑/↓] Move   [Y] Copy Field   [Shift+Y] Copy All   [J] Sections   [Esc] Close",
+            )
+            .style(theme.muted_style())
+            .alignment(Alignment::Center);
+            f.render_widget(help, content_area[1]);
+            return;
+        }
+
+        let Some(data) = &self.inspect_data else {
+            let paragraph = Paragraph::new("Loading container details...")
+                .block(
+                    Block::default()
+                        .title("Container Inspection")
+                        .borders(Borders::ALL)
+                        .border_style(theme.modal_border_style()),
+                )
+                .style(theme.loading_style())
+                .alignment(Alignment::Center);
+            f.render_widget(paragraph, popup_area);
+            return;
+        };
+
+        let content_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(popup_area);
+
+        let (lines, _) = self.inspect_lines(data);
+        let visible_lines: Vec<Line> = lines
+            .into_iter()
+            .skip(self.inspect_scroll)
+            .take(content_area[0].height.saturating_sub(2) as usize)
+            .collect();
+
+        let paragraph = Paragraph::new(visible_lines)
+            .block(
+                Block::default()
+                    .title(format!("Container Inspection: {}", data.name))
+                    .borders(Borders::ALL)
+                    .border_style(theme.modal_border_style()),
+            )
+            .style(theme.normal_style());
+        f.render_widget(paragraph, content_area[0]);
+
+        let help = Paragraph::new(
+            "[↑/↓] Scroll   [Tab] Next Section   [Enter] Expand/Collapse   [A] All   [J] Raw JSON   [Esc] Close",
+        )
+        .style(theme.muted_style())
+        .alignment(Alignment::Center);
+        f.render_widget(help, content_area[1]);
     }
 
-    async fn delete_container(&self, container_name: &str) -> Result<()> {
-        eprintln!("Deleting container: {}", container_name);
-        // TODO: Implement container deletion
-        // Should probably ask for confirmation first
-        Ok(())
+    fn confirm_remove_container(&mut self, container: ContainerInfo) {
+        let mut dialog = ConfirmDialog::new(format!("Remove {}", container.name), "Remove")
+            .detail("Container", container.name.clone())
+            .detail("ID", container.display_id.clone())
+            .detail("Image", container.image.clone())
+            .detail("State", container.status.clone());
+
+        // The daemon refuses to remove a running container unless forced
+        if matches!(
+            container.state.as_str(),
+            "running" | "paused" | "restarting"
+        ) {
+            dialog = dialog
+                .note("The container is running and will be killed if forced.")
+                .option("force", "Force removal (kill the container)", false);
+        }
+        dialog = dialog.option("volumes", "Remove anonymous volumes", false);
+
+        self.remove_dialog = Some((dialog, container));
+    }
+
+    fn render_main_table(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let theme = current_theme();
+
+        if self.containers.is_empty() {
+            // Show loading or empty state with theme
+            let paragraph = Paragraph::new("No containers found or loading...")
+                .block(
+                    Block::default()
+                        .title("Containers")
+                        .borders(Borders::ALL)
+                        .border_style(theme.border_style()),
+                )
+                .style(theme.muted_style());
+            f.render_widget(paragraph, area);
+        } else {
+            // Create table headers with theme
+            let headers = Row::new(vec![
+                Cell::from("Name").style(theme.header_style()),
+                Cell::from("Container ID").style(theme.header_style()),
+                Cell::from("Image").style(theme.header_style()),
+                Cell::from("Command").style(theme.header_style()),
+                Cell::from("Status").style(theme.header_style()),
+                Cell::from("Created").style(theme.header_style()),
+                Cell::from("Ports").style(theme.header_style()),
+                Cell::from("Project").style(theme.header_style()),
+                Cell::from("CPU").style(theme.header_style()),
+                Cell::from("Mem").style(theme.header_style()),
+                Cell::from("Net I/O").style(theme.header_style()),
+                Cell::from("Block I/O").style(theme.header_style()),
+            ]);
+
+            // Create table rows colored by container state
+            let rows: Vec<Row> = self
+                .containers
+                .iter()
+                .enumerate()
+                .map(|(i, container)| {
+                    let style = if i == self.selected_index {
+                        theme.selected_style()
+                    } else {
+                        Self::state_style(&container.state)
+                    };
+
+                    let stats = self
+                        .stats
+                        .get(&container.id)
+                        .and_then(|history| history.latest());
+                    let stat = |format: fn(&ContainerStats) -> String| {
+                        stats.map(format).unwrap_or_else(|| "-".to_string())
+                    };
+
+                    Row::new(vec![
+                        Cell::from(container.name.clone()),
+                        Cell::from(container.display_id.clone()),
+                        Cell::from(container.image.clone()),
+                        Cell::from(container.command.clone()),
+                        Cell::from(container.status.clone()),
+                        Cell::from(container.created_ago.clone()),
+                        Cell::from(container.ports.clone()),
+                        Cell::from(container.compose_project.clone()),
+                        Cell::from(stat(StatsMonitor::format_cpu)),
+                        Cell::from(stat(StatsMonitor::format_memory)),
+                        Cell::from(stat(|s| StatsMonitor::format_io(s.net_rx, s.net_tx))),
+                        Cell::from(stat(|s| {
+                            StatsMonitor::format_io(s.block_read, s.block_write)
+                        })),
+                    ])
+                    .style(style)
+                })
+                .collect();
+
+            let table = Table::new(
+                rows,
+                vec![
+                    Constraint::Percentage(11), // Name
+                    Constraint::Percentage(9),  // Container ID
+                    Constraint::Percentage(11), // Image
+                    Constraint::Percentage(8),  // Command
+                    Constraint::Percentage(9),  // Status
+                    Constraint::Percentage(5),  // Created
+                    Constraint::Percentage(8),  // Ports
+                    Constraint::Percentage(6),  // Project
+                    Constraint::Percentage(5),  // CPU
+                    Constraint::Percentage(10), // Mem
+                    Constraint::Percentage(9),  // Net I/O
+                    Constraint::Percentage(9),  // Block I/O
+                ],
+            )
+            .header(headers)
+            .block(
+                Block::default()
+                    .title(format!("Containers ({})", self.containers.len()))
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .column_spacing(1);
+
+            f.render_widget(table, area);
+        }
+    }
+
+    fn render_kill_modal(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let theme = current_theme();
+        let popup_area = crate::ui::centered_rect(30, 50, area);
+
+        // Clear the background
+        f.render_widget(Clear, popup_area);
+
+        let title = match &self.kill_target {
+            Some(container) => format!("Kill {}", container.name),
+            None => "Kill".to_string(),
+        };
+
+        let items: Vec<ListItem> = KILL_SIGNALS
+            .iter()
+            .enumerate()
+            .map(|(i, signal)| {
+                let style = if i == self.kill_signal_index {
+                    theme.selected_style()
+                } else {
+                    theme.normal_style()
+                };
+                ListItem::new(*signal).style(style)
+            })
+            .collect();
+
+        let content_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(popup_area);
+
+        let list = List::new(items).block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(theme.modal_border_style()),
+        );
+        f.render_widget(list, content_area[0]);
+
+        let help = Paragraph::new("[Enter] Send   [Esc] Cancel")
+            .style(theme.muted_style())
+            .alignment(Alignment::Center);
+        f.render_widget(help, content_area[1]);
     }
 }
 
@@ -107,15 +831,161 @@ impl Component for ContainersUI {
         self.refresh_now().await
     }
 
+    async fn select(&mut self, id: &str) -> bool {
+        // Created since the last refresh maybe
+        if !self.containers.iter().any(|container| container.id == id) {
+            let _ = self.refresh_now().await;
+        }
+        let Some(index) = self
+            .containers
+            .iter()
+            .position(|container| container.id == id)
+        else {
+            return false;
+        };
+
+        // Close what would hide the table
+        self.selected_index = index;
+        self.kill_target = None;
+        self.log_viewer = None;
+        self.stats_container = None;
+        self.show_inspect_modal = false;
+        self.inspect_data = None;
+        self.inspect_scroll = 0;
+        self.json_view = None;
+        true
+    }
+
     async fn tick(&mut self) {
+        self.stats.poll();
+        if let Some(viewer) = &mut self.log_viewer {
+            viewer.poll();
+        }
+        if let Some(pane) = &mut self.terminal_pane {
+            pane.poll();
+            if pane.is_exited() {
+                self.pane_focused = false;
+            }
+        }
+
+        // Pick up results of finished lifecycle actions
+        let mut action_done = false;
+        while let Ok(message) = self.action_rx.try_recv() {
+            self.status_message = Some(message);
+            action_done = true;
+        }
+        if self
+            .status_message
+            .as_ref()
+            .is_some_and(|message| message.is_expired())
+        {
+            self.status_message = None;
+        }
+
         let now = std::time::Instant::now();
-        if now.duration_since(self.last_tick).as_secs() >= 10 {
+        if action_done || now.duration_since(self.last_tick).as_secs() >= 10 {
             self.last_tick = now;
             let _ = self.refresh_now().await;
         }
     }
 
+    async fn handle_key_event(&mut self, key: KeyEvent) -> Result<bool> {
+        // A focused shell pane gets every key except the focus toggle
+        if let Some(pane) = &self.terminal_pane
+            && self.pane_focused
+        {
+            if key.code == KeyCode::F(2) {
+                self.pane_focused = false;
+            } else if key.code == KeyCode::F(3) {
+                self.close_terminal_pane();
+            } else {
+                pane.send_key(key);
+            }
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
     async fn handle_input(&mut self, key: KeyCode) -> Result<bool> {
+        if key == KeyCode::F(2) {
+            self.toggle_terminal_pane().await;
+            return Ok(true);
+        }
+        if key == KeyCode::F(3) && self.terminal_pane.is_some() {
+            self.close_terminal_pane();
+            return Ok(true);
+        }
+
+        // Log viewer takes over the whole tab while open
+        if let Some(viewer) = &mut self.log_viewer {
+            if !viewer.handle_input(key) {
+                self.log_viewer = None;
+            }
+            return Ok(true);
+        }
+
+        if self.stats_container.is_some() {
+            if key == KeyCode::Esc {
+                self.stats_container = None;
+            }
+            return Ok(true);
+        }
+
+        if self.show_inspect_modal {
+            if key == KeyCode::Char('j') {
+                self.toggle_json_view().await;
+            } else if let Some(json_view) = &mut self.json_view
+                && key != KeyCode::Esc
+            {
+                json_view.handle_input(key);
+            } else {
+                self.handle_inspect_input(key);
+            }
+            return Ok(true); // Modal is open, consume all events
+        }
+
+        if let Some((dialog, _)) = &mut self.remove_dialog {
+            match dialog.handle_input(key) {
+                ConfirmOutcome::Pending => {}
+                ConfirmOutcome::Cancelled => self.remove_dialog = None,
+                ConfirmOutcome::Confirmed => {
+                    if let Some((dialog, container)) = self.remove_dialog.take() {
+                        let action = ContainerAction::Remove {
+                            force: dialog.is_checked("force"),
+                            volumes: dialog.is_checked("volumes"),
+                        };
+                        self.run_action(container, action).await;
+                    }
+                }
+            }
+            return Ok(true); // Dialog is open, consume all events
+        }
+
+        // Handle kill dialog input first
+        if self.kill_target.is_some() {
+            match key {
+                KeyCode::Esc => {
+                    self.kill_target = None;
+                }
+                KeyCode::Up => {
+                    self.kill_signal_index = self.kill_signal_index.saturating_sub(1);
+                }
+                KeyCode::Down => {
+                    self.kill_signal_index =
+                        (self.kill_signal_index + 1).min(KILL_SIGNALS.len() - 1);
+                }
+                KeyCode::Enter => {
+                    if let Some(container) = self.kill_target.take() {
+                        let signal = KILL_SIGNALS[self.kill_signal_index].to_string();
+                        self.run_action(container, ContainerAction::Kill(signal))
+                            .await;
+                    }
+                }
+                _ => {}
+            }
+            return Ok(true); // Dialog is open, consume all events
+        }
+
         match key {
             KeyCode::Up => {
                 if self.selected_index > 0 {
@@ -134,20 +1004,64 @@ impl Component for ContainersUI {
                 Ok(true)
             }
             KeyCode::Char('s') => {
-                if let Some(container_name) = self.get_selected_container() {
-                    self.toggle_container_state(container_name).await?;
+                if let Some(container) = self.get_selected_container() {
+                    let container = container.clone();
+                    self.toggle_container_state(container).await;
+                }
+                Ok(true)
+            }
+            KeyCode::Char('t') => {
+                if let Some(container) = self.get_selected_container() {
+                    let container = container.clone();
+                    self.run_action(container, ContainerAction::Restart).await;
+                }
+                Ok(true)
+            }
+            KeyCode::Char('p') => {
+                if let Some(container) = self.get_selected_container() {
+                    let container = container.clone();
+                    self.toggle_container_pause(container).await;
+                }
+                Ok(true)
+            }
+            KeyCode::Char('k') => {
+                if let Some(container) = self.get_selected_container() {
+                    self.kill_target = Some(container.clone());
+                    self.kill_signal_index = 0;
                 }
                 Ok(true)
             }
             KeyCode::Char('l') => {
-                if let Some(container_name) = self.get_selected_container() {
-                    self.show_container_logs(container_name).await?;
+                if let Some(container) = self.get_selected_container() {
+                    let container = container.clone();
+                    self.show_container_logs(&container).await;
+                }
+                Ok(true)
+            }
+            KeyCode::Char('i') => {
+                if let Some(container) = self.get_selected_container() {
+                    let container = container.clone();
+                    self.inspect_container(&container).await;
+                }
+                Ok(true)
+            }
+            KeyCode::Char('m') => {
+                if let Some(container) = self.get_selected_container() {
+                    self.stats_container = Some(container.id.clone());
+                }
+                Ok(true)
+            }
+            KeyCode::Char('x') => {
+                if let Some(container) = self.get_selected_container() {
+                    let container = container.clone();
+                    self.exec_shell(&container);
                 }
                 Ok(true)
             }
             KeyCode::Char('d') => {
-                if let Some(container_name) = self.get_selected_container() {
-                    self.delete_container(container_name).await?;
+                if let Some(container) = self.get_selected_container() {
+                    let container = container.clone();
+                    self.confirm_remove_container(container);
                 }
                 Ok(true)
             }
@@ -156,49 +1070,81 @@ impl Component for ContainersUI {
     }
 
     fn render(&self, f: &mut Frame, area: ratatui::layout::Rect) {
-        let theme = current_theme();
+        // Split the tab when the embedded shell is open
+        let area = if let Some(pane) = &self.terminal_pane {
+            let chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(area);
+            pane.render(f, chunks[1], self.pane_focused);
+            chunks[0]
+        } else {
+            area
+        };
 
-        if self.containers.is_empty() {
-            // Show loading or empty state with theme
-            let paragraph = Paragraph::new("No containers found or loading...")
-                .block(
-                    Block::default()
-                        .title("Containers")
-                        .borders(Borders::ALL)
-                        .border_style(theme.border_style()),
-                )
-                .style(theme.muted_style());
-            f.render_widget(paragraph, area);
+        if let Some(viewer) = &self.log_viewer {
+            viewer.render(f, area);
+            return;
+        }
+
+        if let Some(id) = &self.stats_container {
+            match self.containers.iter().find(|container| &container.id == id) {
+                Some(container) => self.stats.render_detail(f, area, container),
+                None => {
+                    let paragraph = Paragraph::new("Container no longer exists")
+                        .block(Block::default().title("Stats").borders(Borders::ALL))
+                        .style(current_theme().muted_style());
+                    f.render_widget(paragraph, area);
+                }
+            }
+            return;
+        }
+
+        // Reserve a line for the status message if there is one
+        let table_area = if let Some(message) = &self.status_message {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(1)])
+                .split(area);
+
+            let status = Paragraph::new(message.text.clone()).style(message.style());
+            f.render_widget(status, chunks[1]);
+            chunks[0]
         } else {
-            // Create list items with selection highlighting using theme
-            let items: Vec<ListItem> = self
-                .containers
-                .iter()
-                .enumerate()
-                .map(|(i, container)| {
-                    let style = if i == self.selected_index {
-                        theme.selected_style()
-                    } else {
-                        theme.normal_style()
-                    };
-                    ListItem::new(container.clone()).style(style)
-                })
-                .collect();
+            area
+        };
 
-            let list = List::new(items)
-                .block(
-                    Block::default()
-                        .title(format!("Containers ({})", self.containers.len()))
-                        .borders(Borders::ALL)
-                        .border_style(theme.border_style()),
-                )
-                .style(theme.normal_style());
+        self.render_main_table(f, table_area);
 
-            f.render_widget(list, area);
+        // Render modal if active
+        if self.kill_target.is_some() {
+            self.render_kill_modal(f, area);
+        }
+        if self.show_inspect_modal {
+            self.render_inspect_modal(f, area);
+        }
+        if let Some((dialog, _)) = &self.remove_dialog {
+            dialog.render(f, area);
         }
     }
 
     fn render_help(&self) -> &'static str {
-        "[↑/↓] Select   [S] Start/Stop   [L] Logs   [D] Delete   [R/F5] Refresh   [Q] Quit"
+        if self.pane_focused {
+            "Keys go to the shell   [F2] Release Focus   [F3] Close Shell"
+        } else if let Some(viewer) = &self.log_viewer {
+            viewer.render_help()
+        } else if self.stats_container.is_some() {
+            "[Esc] Close Stats"
+        } else if let Some((dialog, _)) = &self.remove_dialog {
+            dialog.render_help()
+        } else if self.json_view.is_some() {
+            "[↑/↓/PgUp/PgDn] Move   [Y] Copy Field   [Shift+Y] Copy All   [J] Sections   [Esc] Close"
+        } else if self.show_inspect_modal {
+            "[↑/↓] Scroll   [Tab/Shift+Tab] Section   [Enter] Expand/Collapse   [A] All   [J] Raw JSON   [Esc] Close"
+        } else if self.kill_target.is_some() {
+            "[↑/↓] Select Signal   [Enter] Kill   [Esc] Cancel"
+        } else {
+            "[↑/↓] Select   [S] Start/Stop   [T] Restart   [P] Pause   [K] Kill   [L] Logs   [I] Inspect   [M] Stats   [X] Exec   [F2] Shell Pane   [F3] Close Shell   [D] Delete   [R/F5] Refresh   [Q] Quit"
+        }
     }
 }
@@ -0,0 +1,543 @@
+use crate::docker::{DockerClient, ImageInfo};
+use crate::image_fs::{self, Change, ImageFilesystem};
+use crate::theme::current_theme;
+
+use crossterm::event::KeyCode;
+use futures::StreamExt;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+// Rows moved by [PgUp]/[PgDn]
+const PAGE_SCROLL: usize = 10;
+
+enum ExplorerEvent {
+    Exported(u64),
+    Analyzing,
+    Done(Result<ImageFilesystem, String>),
+}
+
+enum ExplorerStatus {
+    Exporting(u64),
+    Analyzing,
+    Ready(ImageFilesystem),
+    Failed(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pane {
+    Layers,
+    Files,
+}
+
+// Line of the file tree, directories above their content
+struct TreeRow {
+    depth: usize,
+    name: String,
+    size: u64,
+    is_dir: bool,
+    // None for parents shown only to place their content
+    change: Option<Change>,
+}
+
+/// Files added, modified and removed by each layer of an image, with the
+/// space lost to files that later layers overwrite or delete, like dive
+pub struct ExplorerView {
+    title: String,
+    image_size: u64,
+    status: ExplorerStatus,
+    selected_layer: usize,
+    rows: Vec<TreeRow>,
+    selected_row: usize,
+    focus: Pane,
+    show_wasted: bool,
+    token: CancellationToken,
+    event_rx: mpsc::UnboundedReceiver<ExplorerEvent>,
+}
+
+impl ExplorerView {
+    pub fn start(
+        image: &ImageInfo,
+        client: DockerClient,
+        cancellation_token: &CancellationToken,
+    ) -> Self {
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let token = cancellation_token.child_token();
+        let task_token = token.clone();
+        let image_size = image.size.max(0) as u64;
+        let id = image.id.clone();
+
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = task_token.cancelled() => {}
+                result = Self::run(id, image_size, client, event_tx.clone()) => {
+                    let _ = event_tx.send(ExplorerEvent::Done(result));
+                }
+            }
+        });
+
+        Self {
+            title: image.repo_tag.clone(),
+            image_size,
+            status: ExplorerStatus::Exporting(0),
+            selected_layer: 0,
+            rows: Vec::new(),
+            selected_row: 0,
+            focus: Pane::Layers,
+            show_wasted: false,
+            token,
+            event_rx,
+        }
+    }
+
+    // The archive can be bigger than memory, it goes through a temporary file
+    async fn run(
+        id: String,
+        image_size: u64,
+        client: DockerClient,
+        event_tx: mpsc::UnboundedSender<ExplorerEvent>,
+    ) -> Result<ImageFilesystem, String> {
+        // Random name created exclusively, removed when `archive` is dropped
+        let short_id: String = id.trim_start_matches("sha256:").chars().take(12).collect();
+        let (file, archive) = tempfile::Builder::new()
+            .prefix(&format!("rustocker-{}-", short_id))
+            .suffix(".tar")
+            .tempfile()
+            .map_err(|e| format!("Cannot create a temporary file: {}", e))?
+            .into_parts();
+        let file = tokio::fs::File::from_std(file);
+
+        let mut export = Box::pin(client.save_images(
+            std::slice::from_ref(&id),
+            file,
+            String::new(),
+            image_size,
+        ));
+        while let Some(update) = export.next().await {
+            let update = update.map_err(|e| DockerClient::format_error(&e))?;
+            let _ = event_tx.send(ExplorerEvent::Exported(update.current));
+        }
+
+        let _ = event_tx.send(ExplorerEvent::Analyzing);
+        let path = archive.to_path_buf();
+        tokio::task::spawn_blocking(move || image_fs::analyze(&path))
+            .await
+            .map_err(|e| e.to_string())?
+            .map_err(|e| format!("{:#}", e))
+    }
+
+    /// Apply received events
+    pub fn poll(&mut self) {
+        while let Ok(event) = self.event_rx.try_recv() {
+            match event {
+                ExplorerEvent::Exported(bytes) => self.status = ExplorerStatus::Exporting(bytes),
+                ExplorerEvent::Analyzing => self.status = ExplorerStatus::Analyzing,
+                ExplorerEvent::Done(result) => {
+                    self.status = match result {
+                        Ok(filesystem) => ExplorerStatus::Ready(filesystem),
+                        Err(e) => ExplorerStatus::Failed(e),
+                    };
+                    self.select_layer(0);
+                }
+            }
+        }
+    }
+
+    fn filesystem(&self) -> Option<&ImageFilesystem> {
+        match &self.status {
+            ExplorerStatus::Ready(filesystem) => Some(filesystem),
+            _ => None,
+        }
+    }
+
+    fn select_layer(&mut self, index: usize) {
+        self.selected_layer = index;
+        self.selected_row = 0;
+        self.rows = self
+            .filesystem()
+            .and_then(|filesystem| filesystem.layers.get(index))
+            .map(|layer| Self::tree_rows(&layer.changes))
+            .unwrap_or_default();
+    }
+
+    // Changes are sorted parents first, so a stack of open directories is enough
+    fn tree_rows(changes: &[image_fs::FileChange]) -> Vec<TreeRow> {
+        // Bytes written under each directory by this layer
+        let mut dir_sizes: HashMap<&str, u64> = HashMap::new();
+        for change in changes.iter().filter(|c| c.change != Change::Removed) {
+            for (index, _) in change.path.match_indices('/') {
+                *dir_sizes.entry(&change.path[..index]).or_insert(0) += change.size;
+            }
+        }
+
+        let mut rows = Vec::new();
+        let mut open: Vec<&str> = Vec::new();
+        for change in changes {
+            let parts: Vec<&str> = change.path.split('/').collect();
+            for depth in 0..parts.len() - 1 {
+                let end = parts[..=depth]
+                    .iter()
+                    .map(|part| part.len() + 1)
+                    .sum::<usize>()
+                    - 1;
+                let parent = &change.path[..end];
+                if open.get(depth) == Some(&parent) {
+                    continue;
+                }
+                open.truncate(depth);
+                open.push(parent);
+                rows.push(TreeRow {
+                    depth,
+                    name: parts[depth].to_string(),
+                    size: dir_sizes.get(parent).copied().unwrap_or(0),
+                    is_dir: true,
+                    change: None,
+                });
+            }
+
+            let depth = parts.len() - 1;
+            if change.is_dir {
+                open.truncate(depth);
+                open.push(&change.path);
+            }
+            let size = if change.is_dir && change.change != Change::Removed {
+                dir_sizes.get(change.path.as_str()).copied().unwrap_or(0)
+            } else {
+                change.size
+            };
+            rows.push(TreeRow {
+                depth,
+                name: parts[depth].to_string(),
+                size,
+                is_dir: change.is_dir,
+                change: Some(change.change),
+            });
+        }
+        rows
+    }
+
+    pub fn handle_input(&mut self, key: KeyCode) {
+        let Some(filesystem) = self.filesystem() else {
+            return;
+        };
+        let layers = filesystem.layers.len();
+        let wasted = filesystem.wasted_paths.len();
+
+        match key {
+            KeyCode::Tab | KeyCode::BackTab | KeyCode::Left | KeyCode::Right => {
+                self.focus = match self.focus {
+                    Pane::Layers => Pane::Files,
+                    Pane::Files => Pane::Layers,
+                };
+            }
+            KeyCode::Char('w') => {
+                self.show_wasted = !self.show_wasted;
+                self.selected_row = 0;
+            }
+            key if self.focus == Pane::Layers => {
+                let last = layers.saturating_sub(1);
+                let index = match key {
+                    KeyCode::Up => self.selected_layer.saturating_sub(1),
+                    KeyCode::Down => (self.selected_layer + 1).min(last),
+                    KeyCode::PageUp => self.selected_layer.saturating_sub(PAGE_SCROLL),
+                    KeyCode::PageDown => (self.selected_layer + PAGE_SCROLL).min(last),
+                    KeyCode::Home => 0,
+                    KeyCode::End => last,
+                    _ => return,
+                };
+                if index != self.selected_layer {
+                    self.select_layer(index);
+                }
+            }
+            key => {
+                let rows = if self.show_wasted {
+                    wasted
+                } else {
+                    self.rows.len()
+                };
+                let last = rows.saturating_sub(1);
+                self.selected_row = match key {
+                    KeyCode::Up => self.selected_row.saturating_sub(1),
+                    KeyCode::Down => (self.selected_row + 1).min(last),
+                    KeyCode::PageUp => self.selected_row.saturating_sub(PAGE_SCROLL),
+                    KeyCode::PageDown => (self.selected_row + PAGE_SCROLL).min(last),
+                    KeyCode::Home => 0,
+                    KeyCode::End => last,
+                    _ => self.selected_row,
+                };
+            }
+        }
+    }
+
+    fn pane_border(&self, pane: Pane) -> Style {
+        let theme = current_theme();
+        if self.focus == pane {
+            theme.modal_border_style()
+        } else {
+            theme.border_style()
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let theme = current_theme();
+        let popup_area = crate::ui::centered_rect(95, 90, area);
+
+        // Clear the background
+        f.render_widget(Clear, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(popup_area);
+
+        let help = Paragraph::new(self.render_help())
+            .style(theme.muted_style())
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[1]);
+
+        let filesystem = match &self.status {
+            ExplorerStatus::Ready(filesystem) => filesystem,
+            status => {
+                let (text, style) = match status {
+                    ExplorerStatus::Exporting(bytes) => (
+                        format!(
+                            "Exporting image... {} of about {}",
+                            DockerClient::format_size(*bytes as i64),
+                            DockerClient::format_size(self.image_size as i64)
+                        ),
+                        theme.loading_style(),
+                    ),
+                    ExplorerStatus::Failed(e) => (e.clone(), theme.error_style()),
+                    _ => ("Reading layers...".to_string(), theme.loading_style()),
+                };
+                let paragraph = Paragraph::new(text).style(style).block(
+                    Block::default()
+                        .title(format!("Explore {}", self.title))
+                        .borders(Borders::ALL)
+                        .border_style(theme.modal_border_style()),
+                );
+                f.render_widget(paragraph, chunks[0]);
+                return;
+            }
+        };
+
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(chunks[0]);
+
+        self.render_layers(f, panes[0], filesystem);
+        if self.show_wasted {
+            self.render_wasted(f, panes[1], filesystem);
+        } else {
+            self.render_tree(f, panes[1], filesystem);
+        }
+    }
+
+    fn render_layers(&self, f: &mut Frame, area: Rect, filesystem: &ImageFilesystem) {
+        let theme = current_theme();
+        let efficiency = filesystem.efficiency() * 100.0;
+        let efficiency_style = if efficiency >= 95.0 {
+            theme.success_style()
+        } else if efficiency >= 80.0 {
+            theme.warning_style()
+        } else {
+            theme.error_style()
+        };
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(5)])
+            .split(area);
+
+        let rows: Vec<Row> = filesystem
+            .layers
+            .iter()
+            .enumerate()
+            .map(|(i, layer)| {
+                Row::new(vec![
+                    Cell::from((i + 1).to_string()),
+                    Cell::from(DockerClient::format_size(layer.size as i64)),
+                    Cell::from(layer.created_by.replace('\t', " ")),
+                ])
+                .style(theme.normal_style())
+            })
+            .collect();
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(4),
+                Constraint::Length(10),
+                Constraint::Min(10),
+            ],
+        )
+        .header(Row::new(vec![
+            Cell::from("#").style(theme.header_style()),
+            Cell::from("Size").style(theme.header_style()),
+            Cell::from("Command").style(theme.header_style()),
+        ]))
+        .row_highlight_style(theme.selected_style())
+        .block(
+            Block::default()
+                .title(format!(
+                    "Explore {} ({} layers)",
+                    self.title,
+                    filesystem.layers.len()
+                ))
+                .borders(Borders::ALL)
+                .border_style(self.pane_border(Pane::Layers)),
+        )
+        .column_spacing(1);
+        let mut state = TableState::default().with_selected(Some(self.selected_layer));
+        f.render_stateful_widget(table, chunks[0], &mut state);
+
+        let summary = vec![
+            Line::from(vec![
+                Span::styled("Efficiency: ", theme.highlight_style()),
+                Span::styled(format!("{:.1}%", efficiency), efficiency_style),
+            ]),
+            Line::from(vec![
+                Span::styled("Wasted space: ", theme.highlight_style()),
+                Span::styled(
+                    DockerClient::format_size(filesystem.wasted_size as i64),
+                    theme.normal_style(),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Layers total: ", theme.highlight_style()),
+                Span::styled(
+                    DockerClient::format_size(filesystem.total_size as i64),
+                    theme.normal_style(),
+                ),
+            ]),
+        ];
+        let summary = Paragraph::new(summary).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.border_style()),
+        );
+        f.render_widget(summary, chunks[1]);
+    }
+
+    fn render_tree(&self, f: &mut Frame, area: Rect, filesystem: &ImageFilesystem) {
+        let theme = current_theme();
+
+        let rows: Vec<Row> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let (marker, style) = match row.change {
+                    Some(Change::Added) => ("+", theme.success_style()),
+                    Some(Change::Modified) => ("~", theme.warning_style()),
+                    Some(Change::Removed) => ("-", theme.error_style()),
+                    None => (" ", theme.muted_style()),
+                };
+                let name = if row.is_dir {
+                    format!("{}/", row.name)
+                } else {
+                    row.name.clone()
+                };
+                Row::new(vec![
+                    Cell::from(format!("{} {}{}", marker, "  ".repeat(row.depth), name)),
+                    Cell::from(DockerClient::format_size(row.size as i64)),
+                ])
+                .style(style)
+            })
+            .collect();
+
+        let title = match filesystem.layers.get(self.selected_layer) {
+            Some(layer) => format!(
+                "Layer {} {} ({} changes)",
+                self.selected_layer + 1,
+                layer.digest,
+                layer.changes.len()
+            ),
+            None => "Layer".to_string(),
+        };
+        let table = Table::new(rows, [Constraint::Min(20), Constraint::Length(10)])
+            .row_highlight_style(theme.selected_style())
+            .block(
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(self.pane_border(Pane::Files)),
+            )
+            .column_spacing(1);
+
+        let selected = (self.focus == Pane::Files).then_some(self.selected_row);
+        let mut state = TableState::default().with_selected(selected);
+        f.render_stateful_widget(table, area, &mut state);
+    }
+
+    fn render_wasted(&self, f: &mut Frame, area: Rect, filesystem: &ImageFilesystem) {
+        let theme = current_theme();
+
+        let rows: Vec<Row> = filesystem
+            .wasted_paths
+            .iter()
+            .map(|wasted| {
+                Row::new(vec![
+                    Cell::from(wasted.occurrences.to_string()),
+                    Cell::from(DockerClient::format_size(wasted.wasted as i64)),
+                    Cell::from(format!("/{}", wasted.path)),
+                ])
+                .style(theme.normal_style())
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(6),
+                Constraint::Length(10),
+                Constraint::Min(20),
+            ],
+        )
+        .header(Row::new(vec![
+            Cell::from("Count").style(theme.header_style()),
+            Cell::from("Wasted").style(theme.header_style()),
+            Cell::from("Path").style(theme.header_style()),
+        ]))
+        .row_highlight_style(theme.selected_style())
+        .block(
+            Block::default()
+                .title(format!(
+                    "Wasted Space ({} files, biggest first)",
+                    filesystem.wasted_paths.len()
+                ))
+                .borders(Borders::ALL)
+                .border_style(self.pane_border(Pane::Files)),
+        )
+        .column_spacing(1);
+
+        let selected = (self.focus == Pane::Files).then_some(self.selected_row);
+        let mut state = TableState::default().with_selected(selected);
+        f.render_stateful_widget(table, area, &mut state);
+    }
+
+    pub fn render_help(&self) -> &'static str {
+        match &self.status {
+            ExplorerStatus::Ready(_) if self.show_wasted => {
+                "[↑/↓/PgUp/PgDn] Select   [Tab] Switch Pane   [W] Layer Files   [Esc] Close"
+            }
+            ExplorerStatus::Ready(_) => {
+                "[↑/↓/PgUp/PgDn] Select   [Tab] Switch Pane   [W] Wasted Space   [Esc] Close"
+            }
+            ExplorerStatus::Failed(_) => "[Esc] Close",
+            _ => "[Esc] Cancel",
+        }
+    }
+}
+
+impl Drop for ExplorerView {
+    fn drop(&mut self) {
+        self.token.cancel();
+    }
+}
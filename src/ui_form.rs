@@ -0,0 +1,305 @@
+use crate::theme::current_theme;
+use crate::ui_input::TextInput;
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormOutcome {
+    Pending,
+    Submitted,
+    Cancelled,
+}
+
+/// Checks a trimmed text value, the error is shown under the form
+pub type Validator = fn(&str) -> Result<(), String>;
+
+enum FieldKind {
+    Text {
+        input: TextInput,
+        validator: Validator,
+    },
+    Choice {
+        options: Vec<String>,
+        selected: usize,
+    },
+    Toggle(bool),
+}
+
+struct FormField {
+    key: String,
+    label: String,
+    hint: String,
+    kind: FieldKind,
+    error: Option<String>,
+}
+
+/// Multi-field dialog for create flows: text inputs, choices and toggles,
+/// read back by key once submitted
+pub struct Form {
+    title: String,
+    submit_label: String,
+    fields: Vec<FormField>,
+    focused: usize,
+}
+
+impl Form {
+    pub fn new(title: impl Into<String>, submit_label: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            submit_label: submit_label.into(),
+            fields: Vec::new(),
+            focused: 0,
+        }
+    }
+
+    fn field(mut self, key: &str, label: &str, hint: &str, kind: FieldKind) -> Self {
+        self.fields.push(FormField {
+            key: key.to_string(),
+            label: label.to_string(),
+            hint: hint.to_string(),
+            kind,
+            error: None,
+        });
+        self
+    }
+
+    /// Text field, checked by `validator` before the form can be submitted
+    pub fn text(self, key: &str, label: &str, hint: &str, validator: Validator) -> Self {
+        let kind = FieldKind::Text {
+            input: TextInput::new(),
+            validator,
+        };
+        self.field(key, label, hint, kind)
+    }
+
+    /// One of a fixed set of values, the first is the default.
+    /// Panics without options, there would be nothing to select
+    pub fn choice(self, key: &str, label: &str, hint: &str, options: &[&str]) -> Self {
+        assert!(!options.is_empty(), "choice {} has no options", key);
+        let kind = FieldKind::Choice {
+            options: options.iter().map(|option| option.to_string()).collect(),
+            selected: 0,
+        };
+        self.field(key, label, hint, kind)
+    }
+
+    pub fn toggle(self, key: &str, label: &str, hint: &str, checked: bool) -> Self {
+        self.field(key, label, hint, FieldKind::Toggle(checked))
+    }
+
+    fn find(&self, key: &str) -> Option<&FormField> {
+        self.fields.iter().find(|field| field.key == key)
+    }
+
+    /// Trimmed text or selected choice, empty for unknown keys
+    pub fn value(&self, key: &str) -> &str {
+        match self.find(key).map(|field| &field.kind) {
+            Some(FieldKind::Text { input, .. }) => input.value().trim(),
+            Some(FieldKind::Choice { options, selected }) => &options[*selected],
+            _ => "",
+        }
+    }
+
+    pub fn is_checked(&self, key: &str) -> bool {
+        matches!(
+            self.find(key).map(|field| &field.kind),
+            Some(FieldKind::Toggle(true))
+        )
+    }
+
+    /// Error found by the caller after submission, keeps the form open
+    /// with the field focused
+    pub fn set_error(&mut self, key: &str, error: impl Into<String>) {
+        if let Some(index) = self.fields.iter().position(|field| field.key == key) {
+            self.fields[index].error = Some(error.into());
+            self.focused = index;
+        }
+    }
+
+    // Runs the field validators, focuses the first invalid field
+    fn validate(&mut self) -> bool {
+        for field in &mut self.fields {
+            if let FieldKind::Text { input, validator } = &field.kind {
+                field.error = validator(input.value().trim()).err();
+            }
+        }
+        match self.fields.iter().position(|field| field.error.is_some()) {
+            Some(index) => {
+                self.focused = index;
+                false
+            }
+            None => true,
+        }
+    }
+
+    pub fn handle_input(&mut self, key: KeyCode) -> FormOutcome {
+        let last = self.fields.len().saturating_sub(1);
+        match key {
+            KeyCode::Esc => return FormOutcome::Cancelled,
+            KeyCode::Enter => {
+                if self.validate() {
+                    return FormOutcome::Submitted;
+                }
+            }
+            KeyCode::Up | KeyCode::BackTab => self.focused = self.focused.saturating_sub(1),
+            KeyCode::Down | KeyCode::Tab => self.focused = (self.focused + 1).min(last),
+            key => {
+                let Some(field) = self.fields.get_mut(self.focused) else {
+                    return FormOutcome::Pending;
+                };
+                let changed = match (&mut field.kind, key) {
+                    (FieldKind::Text { input, .. }, key) => input.handle_input(key),
+                    (
+                        FieldKind::Choice { options, selected },
+                        KeyCode::Right | KeyCode::Char(' '),
+                    ) => {
+                        *selected = (*selected + 1) % options.len();
+                        true
+                    }
+                    (FieldKind::Choice { options, selected }, KeyCode::Left) => {
+                        *selected = (*selected + options.len() - 1) % options.len();
+                        true
+                    }
+                    (FieldKind::Toggle(checked), KeyCode::Char(' ')) => {
+                        *checked = !*checked;
+                        true
+                    }
+                    _ => false,
+                };
+                if changed {
+                    field.error = None;
+                }
+            }
+        }
+        FormOutcome::Pending
+    }
+
+    pub fn render_help(&self) -> &'static str {
+        match self.fields.get(self.focused).map(|field| &field.kind) {
+            Some(FieldKind::Choice { .. }) => {
+                "[↑/↓/Tab] Field   [←/→] Change   [Enter] Submit   [Esc] Cancel"
+            }
+            Some(FieldKind::Toggle(_)) => {
+                "[↑/↓/Tab] Field   [Space] Toggle   [Enter] Submit   [Esc] Cancel"
+            }
+            _ => "[↑/↓/Tab] Field   [Enter] Submit   [Esc] Cancel",
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let theme = current_theme();
+
+        // Fields, a blank line and the hint or error of the focused field
+        let height = (self.fields.len() as u16 + 5).min(area.height);
+        let width = (area.width * 2 / 3).max(60).min(area.width);
+        let popup_area = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        // Clear the background
+        f.render_widget(Clear, popup_area);
+
+        let content_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(popup_area);
+
+        let label_width = self
+            .fields
+            .iter()
+            .map(|field| field.label.chars().count())
+            .max()
+            .unwrap_or(0)
+            + 2;
+        let inner_width = popup_area.width.saturating_sub(2);
+
+        let mut lines: Vec<Line> = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let focused = i == self.focused;
+                let label_style = if field.error.is_some() {
+                    theme.error_style()
+                } else if focused {
+                    theme.selected_style()
+                } else {
+                    theme.highlight_style()
+                };
+                let label = Span::styled(
+                    format!("{:<width$}", field.label, width = label_width),
+                    label_style,
+                );
+                match &field.kind {
+                    FieldKind::Text { input, .. } => {
+                        input.line(label, theme.normal_style(), inner_width, focused)
+                    }
+                    FieldKind::Choice { options, selected } => {
+                        let value = if focused {
+                            format!("◀ {} ▶", options[*selected])
+                        } else {
+                            options[*selected].clone()
+                        };
+                        Line::from(vec![label, Span::styled(value, theme.normal_style())])
+                    }
+                    FieldKind::Toggle(checked) => {
+                        let checkbox = if *checked { "[x]" } else { "[ ]" };
+                        Line::from(vec![label, Span::styled(checkbox, theme.normal_style())])
+                    }
+                }
+            })
+            .collect();
+
+        lines.push(Line::from(""));
+        if let Some(field) = self.fields.get(self.focused) {
+            lines.push(Line::from(match &field.error {
+                Some(error) => Span::styled(error.clone(), theme.error_style()),
+                None => Span::styled(field.hint.clone(), theme.muted_style()),
+            }));
+        }
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .title(self.title.clone())
+                .borders(Borders::ALL)
+                .border_style(theme.modal_border_style()),
+        );
+        f.render_widget(paragraph, content_area[0]);
+
+        let help = Paragraph::new(format!("[Enter] {}   [Esc] Cancel", self.submit_label))
+            .style(theme.muted_style())
+            .alignment(Alignment::Center);
+        f.render_widget(help, content_area[1]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn choice_cycles_through_options() {
+        let mut form = Form::new("Test", "Save").choice("driver", "Driver", "", &["a", "b", "c"]);
+        assert_eq!(form.value("driver"), "a");
+        form.handle_input(KeyCode::Left);
+        assert_eq!(form.value("driver"), "c");
+        form.handle_input(KeyCode::Right);
+        form.handle_input(KeyCode::Char(' '));
+        assert_eq!(form.value("driver"), "b");
+    }
+
+    #[test]
+    #[should_panic(expected = "choice driver has no options")]
+    fn choice_without_options_is_rejected() {
+        let _ = Form::new("Test", "Save").choice("driver", "Driver", "", &[]);
+    }
+}
@@ -0,0 +1,221 @@
+use crate::docker::{DockerClient, ImageLayer};
+use crate::theme::current_theme;
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState, Wrap},
+};
+
+// Layers moved by [PgUp]/[PgDn]
+const PAGE_SCROLL: usize = 10;
+
+// How many of the biggest layers are highlighted
+const LARGEST_HIGHLIGHTED: usize = 3;
+
+/// Layers of an image with their size share, to spot what bloats it
+pub struct HistoryView {
+    title: String,
+    layers: Vec<ImageLayer>,
+    // Indexes into `layers` in display order
+    order: Vec<usize>,
+    total_size: i64,
+    // Rank by size of each layer, 0 = largest
+    size_rank: Vec<usize>,
+    selected: usize,
+    sort_by_size: bool,
+}
+
+impl HistoryView {
+    pub fn new(title: impl Into<String>, layers: Vec<ImageLayer>) -> Self {
+        let total_size = layers.iter().map(|layer| layer.size.max(0)).sum();
+
+        let mut by_size: Vec<usize> = (0..layers.len()).collect();
+        by_size.sort_by_key(|&i| std::cmp::Reverse(layers[i].size));
+        let mut size_rank = vec![0; layers.len()];
+        for (rank, &i) in by_size.iter().enumerate() {
+            size_rank[i] = rank;
+        }
+
+        Self {
+            title: title.into(),
+            order: (0..layers.len()).collect(),
+            layers,
+            total_size,
+            size_rank,
+            selected: 0,
+            sort_by_size: false,
+        }
+    }
+
+    fn toggle_sort(&mut self) {
+        self.sort_by_size = !self.sort_by_size;
+        if self.sort_by_size {
+            self.order.sort_by_key(|&i| self.size_rank[i]);
+        } else {
+            self.order.sort();
+        }
+        self.selected = 0;
+    }
+
+    pub fn handle_input(&mut self, key: KeyCode) {
+        let last = self.layers.len().saturating_sub(1);
+        match key {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => self.selected = (self.selected + 1).min(last),
+            KeyCode::PageUp => self.selected = self.selected.saturating_sub(PAGE_SCROLL),
+            KeyCode::PageDown => self.selected = (self.selected + PAGE_SCROLL).min(last),
+            KeyCode::Home => self.selected = 0,
+            KeyCode::End => self.selected = last,
+            KeyCode::Char('s') => self.toggle_sort(),
+            _ => {}
+        }
+    }
+
+    fn layer_style(&self, index: usize) -> Style {
+        let theme = current_theme();
+        let layer = &self.layers[index];
+        match self.size_rank[index] {
+            _ if layer.size <= 0 => theme.muted_style(),
+            0 => theme.error_style(),
+            rank if rank < LARGEST_HIGHLIGHTED => theme.warning_style(),
+            _ => theme.normal_style(),
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let theme = current_theme();
+        let popup_area = crate::ui::centered_rect(90, 85, area);
+
+        // Clear the background
+        f.render_widget(Clear, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(0),
+                Constraint::Length(6),
+                Constraint::Length(1),
+            ])
+            .split(popup_area);
+
+        let headers = Row::new(vec![
+            Cell::from("#").style(theme.header_style()),
+            Cell::from("Size").style(theme.header_style()),
+            Cell::from("Share").style(theme.header_style()),
+            Cell::from("Created").style(theme.header_style()),
+            Cell::from("Created By").style(theme.header_style()),
+        ]);
+
+        // Layers are numbered from the base image up
+        let rows: Vec<Row> = self
+            .order
+            .iter()
+            .map(|&i| {
+                let layer = &self.layers[i];
+                let share = if self.total_size > 0 {
+                    format!(
+                        "{:.1}%",
+                        layer.size.max(0) as f64 * 100.0 / self.total_size as f64
+                    )
+                } else {
+                    "-".to_string()
+                };
+                Row::new(vec![
+                    Cell::from((self.layers.len() - i).to_string()),
+                    Cell::from(layer.size_formatted.clone()),
+                    Cell::from(share),
+                    Cell::from(layer.created_ago.clone()),
+                    Cell::from(layer.created_by.replace('\t', " ")),
+                ])
+                .style(self.layer_style(i))
+            })
+            .collect();
+
+        let sort = if self.sort_by_size {
+            "by size"
+        } else {
+            "newest first"
+        };
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(4),
+                Constraint::Length(10),
+                Constraint::Length(7),
+                Constraint::Length(8),
+                Constraint::Min(20),
+            ],
+        )
+        .header(headers)
+        .row_highlight_style(theme.selected_style())
+        .block(
+            Block::default()
+                .title(format!(
+                    "History: {} ({} layers, {}, {})",
+                    self.title,
+                    self.layers.len(),
+                    DockerClient::format_size(self.total_size),
+                    sort
+                ))
+                .borders(Borders::ALL)
+                .border_style(theme.modal_border_style()),
+        )
+        .column_spacing(1);
+
+        let mut state = TableState::default().with_selected(Some(self.selected));
+        f.render_stateful_widget(table, chunks[0], &mut state);
+
+        // Full instruction of the selected layer, the table cuts it
+        let detail = match self.order.get(self.selected).map(|&i| &self.layers[i]) {
+            Some(layer) => {
+                let mut lines = vec![Line::from(vec![
+                    Span::styled("Layer: ", theme.highlight_style()),
+                    Span::styled(layer.id.clone(), theme.normal_style()),
+                ])];
+                if !layer.tags.is_empty() {
+                    lines.push(Line::from(vec![
+                        Span::styled("Tags: ", theme.highlight_style()),
+                        Span::styled(layer.tags.join(", "), theme.normal_style()),
+                    ]));
+                }
+                if !layer.comment.is_empty() {
+                    lines.push(Line::from(vec![
+                        Span::styled("Comment: ", theme.highlight_style()),
+                        Span::styled(layer.comment.clone(), theme.normal_style()),
+                    ]));
+                }
+                lines.push(Line::from(Span::styled(
+                    layer.created_by.clone(),
+                    theme.info_style(),
+                )));
+                lines
+            }
+            None => vec![Line::from(Span::styled("No layers", theme.muted_style()))],
+        };
+        let detail = Paragraph::new(detail)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(theme.modal_border_style()),
+            )
+            .wrap(Wrap { trim: false });
+        f.render_widget(detail, chunks[1]);
+
+        let help = Paragraph::new(self.render_help())
+            .style(theme.muted_style())
+            .alignment(Alignment::Center);
+        f.render_widget(help, chunks[2]);
+    }
+
+    pub fn render_help(&self) -> &'static str {
+        if self.sort_by_size {
+            "[↑/↓/PgUp/PgDn] Select   [S] Sort Newest First   [Esc] Close"
+        } else {
+            "[↑/↓/PgUp/PgDn] Select   [S] Sort By Size   [Esc] Close"
+        }
+    }
+}
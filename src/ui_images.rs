@@ -1,18 +1,50 @@
-use crate::components::Component;
-use crate::docker::{DockerClient, ImageInfo, ImageInspectDetails};
+use crate::components::{Component, StatusMessage};
+use crate::docker::{DockerClient, ImageFilters, ImageInfo, ImageInspectDetails};
+use crate::registry_auth;
 use crate::theme::current_theme;
+use crate::ui_build::{BuildDialog, BuildDialogOutcome, BuildView};
+use crate::ui_compare::CompareView;
+use crate::ui_confirm::{ConfirmDialog, ConfirmOutcome};
+use crate::ui_explorer::ExplorerView;
+use crate::ui_history::HistoryView;
+use crate::ui_input::{TextInput, expand_home};
+use crate::ui_json::JsonView;
+use crate::ui_progress::ProgressView;
 
 use async_trait::async_trait;
 use color_eyre::Result;
 use crossterm::event::KeyCode;
+use futures::StreamExt;
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
 };
-use std::sync::Arc;
-use tokio::sync::Mutex;
+use regex::Regex;
+use std::collections::HashSet;
+use std::sync::{Arc, OnceLock};
+use tokio::sync::{Mutex, mpsc};
+use tokio_util::sync::CancellationToken;
+
+// Images listed in the prune preview, biggest first
+const PRUNE_PREVIEW_LEN: usize = 10;
+
+// What the text prompt is asking for
+enum ImagePrompt {
+    Pull,
+    Tag(ImageInfo),
+    Push(ImageInfo),
+    Save(Vec<ImageInfo>),
+    Load,
+    Label,
+}
+
+// Outcome of a background image removal
+struct RemovalResult {
+    target: String,
+    result: Result<Vec<String>, String>,
+}
 
 pub struct ImagesUI {
     tab_num: usize,
@@ -24,10 +56,51 @@ pub struct ImagesUI {
     show_inspect_modal: bool,
     inspect_data: Option<ImageInspectDetails>,
     inspect_scroll: usize,
+    // Raw JSON view of the inspected image, replaces the fields when open
+    json_view: Option<JsonView>,
+    status_message: Option<StatusMessage>,
+    // Removal confirmation, background result and the daemon's report
+    delete_dialog: Option<(ConfirmDialog, ImageInfo)>,
+    removal_tx: mpsc::UnboundedSender<RemovalResult>,
+    removal_rx: mpsc::UnboundedReceiver<RemovalResult>,
+    removal_report: Option<(String, Vec<String>)>,
+    report_scroll: usize,
+    cancellation_token: CancellationToken,
+    // Pull or push running in background, its progress modal can be hidden
+    transfer_view: Option<ProgressView>,
+    show_transfer_view: bool,
+    // Reference typed by the user, with the last parse error
+    prompt: Option<(ImagePrompt, TextInput)>,
+    prompt_error: Option<String>,
+    // Tags to remove from an image with several of them
+    untag_dialog: Option<(ConfirmDialog, ImageInfo)>,
+    // Images whose extra tags are listed under their row
+    expanded: HashSet<String>,
+    // IDs of images marked with [Space] for actions on several images
+    marked: HashSet<String>,
+    // Build dialog, then the build output running in background
+    build_dialog: Option<BuildDialog>,
+    build_view: Option<BuildView>,
+    show_build_view: bool,
+    history_view: Option<HistoryView>,
+    // Layer contents of an image, exported in background
+    explorer_view: Option<ExplorerView>,
+    // Two marked images side by side
+    compare_view: Option<CompareView>,
+    // Image to select once the list contains it (e.g. just built)
+    select_after_refresh: Option<String>,
+    filters: ImageFilters,
+    // Preview of what a prune removes, confirmed like a deletion
+    prune_dialog: Option<ConfirmDialog>,
 }
 
 impl ImagesUI {
-    pub fn new(docker_client: Arc<Mutex<DockerClient>>, tab_num: usize) -> Self {
+    pub fn new(
+        docker_client: Arc<Mutex<DockerClient>>,
+        tab_num: usize,
+        cancellation_token: CancellationToken,
+    ) -> Self {
+        let (removal_tx, removal_rx) = mpsc::unbounded_channel();
         Self {
             tab_num,
             docker_client,
@@ -37,14 +110,47 @@ impl ImagesUI {
             show_inspect_modal: false,
             inspect_data: None,
             inspect_scroll: 0,
+            json_view: None,
+            status_message: None,
+            delete_dialog: None,
+            removal_tx,
+            removal_rx,
+            removal_report: None,
+            report_scroll: 0,
+            cancellation_token,
+            transfer_view: None,
+            show_transfer_view: false,
+            prompt: None,
+            prompt_error: None,
+            untag_dialog: None,
+            expanded: HashSet::new(),
+            marked: HashSet::new(),
+            build_dialog: None,
+            build_view: None,
+            show_build_view: false,
+            history_view: None,
+            explorer_view: None,
+            compare_view: None,
+            select_after_refresh: None,
+            filters: ImageFilters::default(),
+            prune_dialog: None,
         }
     }
 
     async fn refresh_now(&mut self) -> Result<()> {
         let client = self.docker_client.lock().await;
-        match client.list_images().await {
+        match client.list_images(&self.filters).await {
             Ok(images) => {
                 self.images = images;
+                // Forget marks of images removed meanwhile
+                let images = &self.images;
+                self.marked
+                    .retain(|id| images.iter().any(|image| &image.id == id));
+                if let Some(id) = self.select_after_refresh.take()
+                    && let Some(index) = self.images.iter().position(|image| image.id == id)
+                {
+                    self.selected_index = index;
+                }
                 // Adjust selected index if necessary
                 if self.selected_index >= self.images.len() && !self.images.is_empty() {
                     self.selected_index = self.images.len() - 1;
@@ -62,30 +168,885 @@ impl ImagesUI {
         self.images.get(self.selected_index)
     }
 
-    async fn delete_image(&self, image: &ImageInfo) -> Result<()> {
-        eprintln!("Deleting image: {}", image.repo_tag);
-        // TODO: Implement image deletion using image.id
-        // Should ask for confirmation and handle dependencies
+    async fn confirm_delete_image(&mut self, image: ImageInfo) {
+        let client = self.docker_client.lock().await;
+        let repo_tags = &image.repo_tags;
+
+        // Only look up users when the daemon didn't report the image as unused
+        let containers = if image.containers == 0 {
+            Ok(Vec::new())
+        } else {
+            client.containers_using_image(&image.id).await
+        };
+        drop(client);
+
+        let mut dialog = ConfirmDialog::new(format!("Delete {}", image.repo_tag), "Delete")
+            .detail("Image", image.repo_tag.clone())
+            .detail("ID", image.display_id.clone())
+            .detail("Size", image.size_formatted.clone());
+        if repo_tags.len() > 1 {
+            dialog = dialog
+                .detail("Tags", repo_tags.join(", "))
+                .note("Deleting by ID with several tags requires force.");
+        }
+
+        match &containers {
+            Ok(containers) if !containers.is_empty() => {
+                dialog = dialog.note(format!(
+                    "Used by {} container(s), the daemon refuses unless forced:",
+                    containers.len()
+                ));
+                for container in containers {
+                    dialog = dialog.note(format!("  {} ({})", container.name, container.state));
+                }
+            }
+            Ok(_) => {}
+            // Keep the in-use warning even without the container names
+            Err(e) => {
+                let users = if image.containers > 0 {
+                    format!("Used by {} container(s)", image.containers)
+                } else {
+                    "May be used by containers".to_string()
+                };
+                dialog = dialog
+                    .note(format!("{}, the daemon refuses unless forced.", users))
+                    .note(format!(
+                        "Listing them failed: {}",
+                        DockerClient::format_error(e)
+                    ));
+            }
+        }
+
+        if repo_tags.len() > 1 {
+            dialog = dialog.option(
+                "untag",
+                format!("Only untag {} (keep the other tags)", image.repo_tag),
+                true,
+            );
+        }
+        dialog = dialog.option("force", "Force removal", false).option(
+            "noprune",
+            "Keep untagged parent images (no prune)",
+            false,
+        );
+
+        self.delete_dialog = Some((dialog, image));
+    }
+
+    async fn delete_image(&mut self, image: ImageInfo, untag: bool, force: bool, no_prune: bool) {
+        // Untagging removes just the reference, deleting goes by ID
+        let target = if untag {
+            image.repo_tag.clone()
+        } else {
+            image.id.clone()
+        };
+        self.status_message = Some(StatusMessage::info(format!(
+            "Removing {}...",
+            image.repo_tag
+        )));
+
+        let client = self.docker_client.lock().await.clone();
+        let removal_tx = self.removal_tx.clone();
+
+        tokio::spawn(async move {
+            let result = client
+                .remove_image(&target, force, no_prune)
+                .await
+                .map_err(|e| DockerClient::format_error(&e));
+            let _ = removal_tx.send(RemovalResult {
+                target: image.repo_tag,
+                result,
+            });
+        });
+    }
+
+    fn toggle_dangling_filter(&mut self) {
+        self.filters.dangling = !self.filters.dangling;
+        self.selected_index = 0;
+    }
+
+    fn toggle_unused_filter(&mut self) {
+        self.filters.unused = !self.filters.unused;
+        self.selected_index = 0;
+    }
+
+    fn open_label_prompt(&mut self) {
+        let input = TextInput::with_value(self.filters.label.clone().unwrap_or_default());
+        self.prompt = Some((ImagePrompt::Label, input));
+        self.prompt_error = None;
+    }
+
+    // "dangling, unused, label=maintainer" for the table title
+    fn describe_filters(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if self.filters.dangling {
+            parts.push("dangling".to_string());
+        }
+        if self.filters.unused {
+            parts.push("unused".to_string());
+        }
+        if let Some(label) = &self.filters.label {
+            parts.push(format!("label={}", label));
+        }
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+
+    async fn compare_marked(&mut self) {
+        // In table order, the selection alone doesn't count
+        let marked: Vec<&ImageInfo> = self
+            .images
+            .iter()
+            .filter(|image| self.marked.contains(&image.id))
+            .collect();
+        let [left, right] = marked.as_slice() else {
+            self.status_message = Some(StatusMessage::error(
+                "Mark two images with [Space] to compare them",
+            ));
+            return;
+        };
+        let (left, right) = ((*left).clone(), (*right).clone());
+
+        let client = self.docker_client.lock().await;
+        let details = match (
+            client.inspect_image(&left.id).await,
+            client.inspect_image(&right.id).await,
+        ) {
+            (Ok(left), Ok(right)) => (left, right),
+            (Err(e), _) | (_, Err(e)) => {
+                self.status_message = Some(StatusMessage::error(format!(
+                    "Failed to inspect images: {}",
+                    DockerClient::format_error(&e)
+                )));
+                return;
+            }
+        };
+        drop(client);
+
+        self.compare_view = Some(CompareView::new(
+            left.repo_tag.clone(),
+            &details.0,
+            right.repo_tag.clone(),
+            &details.1,
+        ));
+    }
+
+    /// Lists what `docker image prune` would remove, with and without `-a`,
+    /// the user picks one in the dialog. The label filter applies to both
+    async fn confirm_prune(&mut self) {
+        let client = self.docker_client.lock().await;
+        let label = self.filters.label.as_deref();
+        let unused = match client.prune_candidates(label).await {
+            Ok(unused) => unused,
+            Err(e) => {
+                self.status_message = Some(StatusMessage::error(format!(
+                    "Failed to list unused images: {}",
+                    DockerClient::format_error(&e)
+                )));
+                return;
+            }
+        };
+        drop(client);
+
+        let (dangling, tagged): (Vec<ImageInfo>, Vec<ImageInfo>) = unused
+            .into_iter()
+            .partition(|image| image.repo_tags.is_empty());
+        if dangling.is_empty() && tagged.is_empty() {
+            self.status_message = Some(StatusMessage::info("Nothing to prune"));
+            return;
+        }
+
+        let summary = |images: &[ImageInfo]| {
+            let size = images.iter().map(|image| image.size.max(0)).sum();
+            format!(
+                "{} image(s), {}",
+                images.len(),
+                DockerClient::format_size(size)
+            )
+        };
+        let mut dialog = ConfirmDialog::new("Prune Images", "Prune")
+            .detail("Dangling", summary(&dangling))
+            .detail("Unused tagged", summary(&tagged));
+        if let Some(label) = label {
+            dialog = dialog.detail("Label", label.to_string());
+        }
+        dialog = dialog.note("Sizes are upper bounds, layers shared with kept images stay.");
+
+        // The list can be long, show the biggest first
+        let mut listed: Vec<&ImageInfo> = dangling.iter().chain(&tagged).collect();
+        listed.sort_by_key(|image| std::cmp::Reverse(image.size));
+        for image in listed.iter().take(PRUNE_PREVIEW_LEN) {
+            dialog = dialog.note(format!(
+                "  {} {} ({})",
+                image.display_id, image.repo_tag, image.size_formatted
+            ));
+        }
+        if listed.len() > PRUNE_PREVIEW_LEN {
+            dialog = dialog.note(format!(
+                "  ...and {} more",
+                listed.len() - PRUNE_PREVIEW_LEN
+            ));
+        }
+
+        if !tagged.is_empty() {
+            dialog = dialog.option(
+                "all",
+                "Also remove unused tagged images (prune -a)",
+                dangling.is_empty(),
+            );
+        }
+        self.prune_dialog = Some(dialog);
+    }
+
+    async fn prune_images(&mut self, all: bool) {
+        self.status_message = Some(StatusMessage::info("Pruning images..."));
+
+        let client = self.docker_client.lock().await.clone();
+        let removal_tx = self.removal_tx.clone();
+        let label = self.filters.label.clone();
+
+        tokio::spawn(async move {
+            let result = client.prune_images(all, label.as_deref()).await;
+            let (target, result) = match result {
+                Ok((report, reclaimed)) => {
+                    let deleted = report
+                        .iter()
+                        .filter(|entry| entry.starts_with("Deleted"))
+                        .count();
+                    (
+                        format!(
+                            "{} layer(s), {} reclaimed",
+                            deleted,
+                            DockerClient::format_size(reclaimed)
+                        ),
+                        Ok(report),
+                    )
+                }
+                Err(e) => (
+                    "unused images".to_string(),
+                    Err(DockerClient::format_error(&e)),
+                ),
+            };
+            let _ = removal_tx.send(RemovalResult { target, result });
+        });
+    }
+
+    /// Prompt for a reference, prefilled with the selected image's tag
+    fn open_prompt(&mut self, kind: ImagePrompt) {
+        let input = match self.get_selected_image() {
+            Some(image) if image.repo_tag != "<none>:<none>" => {
+                TextInput::with_value(image.repo_tag.clone())
+            }
+            _ => TextInput::new(),
+        };
+        self.prompt = Some((kind, input));
+        self.prompt_error = None;
+    }
+
+    /// Marked images in table order, or the selected one when none is marked
+    fn target_images(&self) -> Vec<ImageInfo> {
+        let marked: Vec<ImageInfo> = self
+            .images
+            .iter()
+            .filter(|image| self.marked.contains(&image.id))
+            .cloned()
+            .collect();
+        if marked.is_empty() {
+            self.get_selected_image().cloned().into_iter().collect()
+        } else {
+            marked
+        }
+    }
+
+    fn toggle_mark(&mut self) {
+        if let Some(image) = self.get_selected_image() {
+            let id = image.id.clone();
+            if !self.marked.remove(&id) {
+                self.marked.insert(id);
+            }
+        }
+    }
+
+    fn open_save_prompt(&mut self) {
+        if self.reopen_running_transfer() {
+            return;
+        }
+        let images = self.target_images();
+        let file_name = match images.as_slice() {
+            [] => return,
+            [image] if !image.repo_tags.is_empty() => {
+                format!("{}.tar", image.repo_tag.replace(['/', ':'], "_"))
+            }
+            [image] => format!("{}.tar", image.display_id.trim_end_matches('.')),
+            _ => format!(
+                "images-{}.tar",
+                chrono::Local::now().format("%Y%m%d-%H%M%S")
+            ),
+        };
+        self.prompt = Some((ImagePrompt::Save(images), TextInput::with_value(file_name)));
+        self.prompt_error = None;
+    }
+
+    fn open_load_prompt(&mut self) {
+        if self.reopen_running_transfer() {
+            return;
+        }
+        self.prompt = Some((ImagePrompt::Load, TextInput::new()));
+        self.prompt_error = None;
+    }
+
+    async fn start_save(&mut self, images: &[ImageInfo], path: &str) -> Result<(), String> {
+        if path.is_empty() {
+            return Err("Type the archive path".to_string());
+        }
+        let path = expand_home(path);
+        // Never overwrites, the check and the creation are one step
+        let file = tokio::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .await
+            .map_err(|e| match e.kind() {
+                std::io::ErrorKind::AlreadyExists => format!("{} already exists", path.display()),
+                _ => format!("Cannot create {}: {}", path.display(), e),
+            })?;
+        // A failed or cancelled save drops the stream, which removes the partial archive
+        let partial = tempfile::TempPath::try_from_path(&path).map_err(|e| {
+            let _ = std::fs::remove_file(&path);
+            format!("Cannot resolve {}: {}", path.display(), e)
+        })?;
+        let mut partial = Some(partial);
+
+        // Tags are saved only when asked for by name, untagged images go by ID
+        let names: Vec<String> = images
+            .iter()
+            .flat_map(|image| {
+                if image.repo_tags.is_empty() {
+                    vec![image.id.clone()]
+                } else {
+                    image.repo_tags.clone()
+                }
+            })
+            .collect();
+        let total = images.iter().map(|image| image.size.max(0) as u64).sum();
+        let label = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.display().to_string());
+
+        let client = self.docker_client.lock().await.clone();
+        let stream = client
+            .save_images(&names, file, label, total)
+            .map(move |update| {
+                // "Saved" is the last update, sent once the file is synced
+                if let Ok(update) = &update
+                    && update.status == "Saved"
+                    && let Some(partial) = partial.take()
+                {
+                    let _ = partial.keep();
+                }
+                update
+            });
+        self.transfer_view = Some(ProgressView::start(
+            format!("Save {} image(s) to {}", images.len(), path.display()),
+            stream,
+            &self.cancellation_token,
+        ));
+        self.show_transfer_view = true;
         Ok(())
     }
 
-    async fn pull_image(&self, image: &ImageInfo) -> Result<()> {
-        if image.repo_tag == "<none>:<none>" {
-            eprintln!("Cannot pull image without tag");
-            return Ok(());
+    async fn start_load(&mut self, path: &str) -> Result<(), String> {
+        if path.is_empty() {
+            return Err("Type the archive path".to_string());
         }
+        let path = expand_home(path);
+        let file = tokio::fs::File::open(&path)
+            .await
+            .map_err(|e| format!("Cannot open {}: {}", path.display(), e))?;
+        let total = file
+            .metadata()
+            .await
+            .map(|metadata| metadata.len())
+            .unwrap_or(0);
+        let label = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.display().to_string());
 
-        eprintln!("Pulling image: {}", image.repo_tag);
-        // TODO: Implement image pull
-        // Should show progress if possible
+        let client = self.docker_client.lock().await.clone();
+        let stream = client.load_images(file, label, total);
+        self.transfer_view = Some(ProgressView::start(
+            format!("Load {}", path.display()),
+            stream,
+            &self.cancellation_token,
+        ));
+        self.show_transfer_view = true;
         Ok(())
     }
 
+    /// "repo:tag" as accepted by the daemon, the registry host is optional
+    fn validate_tag(reference: &str) -> Result<(), String> {
+        static TAG_REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = TAG_REGEX.get_or_init(|| {
+            let component = r"[a-z0-9]+(?:(?:[._]|__|-+)[a-z0-9]+)*";
+            Regex::new(&format!(
+                r"^(?:[a-zA-Z0-9.-]+(?::[0-9]+)?/)?{c}(?:/{c})*(?::[\w][\w.-]{{0,127}})?$",
+                c = component
+            ))
+            .expect("valid tag regex")
+        });
+
+        if reference.is_empty() {
+            Err("Type the new tag, e.g. myregistry:5000/app:1.0".to_string())
+        } else if !regex.is_match(reference) {
+            Err(format!(
+                "Invalid tag {}: lowercase name, optional :tag",
+                reference
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    async fn tag_image(&mut self, image: ImageInfo, reference: &str) {
+        let client = self.docker_client.lock().await;
+        match client.tag_image(&image.id, reference).await {
+            Ok(()) => {
+                drop(client);
+                self.status_message = Some(StatusMessage::info(format!(
+                    "Tagged {} as {}",
+                    image.display_id, reference
+                )));
+                self.select_after_refresh = Some(image.id.clone());
+                self.expanded.insert(image.id);
+                let _ = self.refresh_now().await;
+            }
+            Err(e) => {
+                self.status_message = Some(StatusMessage::error(format!(
+                    "Failed to tag {}: {}",
+                    image.repo_tag,
+                    DockerClient::format_error(&e)
+                )));
+            }
+        }
+    }
+
+    fn confirm_untag_image(&mut self, image: ImageInfo) {
+        if image.repo_tags.len() < 2 {
+            self.status_message = Some(StatusMessage::error(format!(
+                "{} has a single tag, use [D] to delete it",
+                image.repo_tag
+            )));
+            return;
+        }
+
+        let mut dialog =
+            ConfirmDialog::new(format!("Remove tags of {}", image.display_id), "Untag")
+                .detail("ID", image.display_id.clone())
+                .note("The image is kept, at least one tag must remain.");
+        for tag in &image.repo_tags {
+            dialog = dialog.option(tag.clone(), tag.clone(), false);
+        }
+        self.untag_dialog = Some((dialog, image));
+    }
+
+    fn untag_image(&mut self, image: ImageInfo, tags: Vec<String>) {
+        if tags.is_empty() {
+            self.status_message = Some(StatusMessage::error("No tag selected"));
+            return;
+        }
+        if tags.len() >= image.repo_tags.len() {
+            self.status_message = Some(StatusMessage::error(
+                "Keep at least one tag, use [D] to delete the image",
+            ));
+            return;
+        }
+        self.status_message = Some(StatusMessage::info(format!(
+            "Removing {} tag(s)...",
+            tags.len()
+        )));
+
+        let docker_client = Arc::clone(&self.docker_client);
+        let removal_tx = self.removal_tx.clone();
+
+        tokio::spawn(async move {
+            let client = docker_client.lock().await.clone();
+            let mut report = Vec::new();
+            let mut result = Ok(());
+            // Removing a reference of an image with other tags only untags it
+            for tag in &tags {
+                match client.remove_image(tag, false, false).await {
+                    Ok(lines) => report.extend(lines),
+                    Err(e) => {
+                        result = Err(format!("{}: {}", tag, DockerClient::format_error(&e)));
+                        break;
+                    }
+                }
+            }
+            let _ = removal_tx.send(RemovalResult {
+                target: tags.join(", "),
+                result: result.map(|()| report),
+            });
+        });
+    }
+
+    /// Split "reference [--platform os/arch]" typed in the pull prompt
+    fn parse_pull_args(input: &str) -> Result<(String, Option<String>), String> {
+        let mut reference = None;
+        let mut platform = None;
+        let mut args = input.split_whitespace();
+
+        while let Some(arg) = args.next() {
+            if arg == "--platform" {
+                match args.next() {
+                    Some(value) => platform = Some(value.to_string()),
+                    None => return Err("--platform needs a value, e.g. linux/arm64".to_string()),
+                }
+            } else if let Some(value) = arg.strip_prefix("--platform=") {
+                platform = Some(value.to_string());
+            } else if arg.starts_with('-') {
+                return Err(format!("Unknown option {}", arg));
+            } else if reference.is_some() {
+                return Err(format!("Unexpected argument {}", arg));
+            } else {
+                reference = Some(arg.to_string());
+            }
+        }
+
+        let Some(reference) = reference else {
+            return Err("Type an image reference, e.g. nginx:latest".to_string());
+        };
+        if platform
+            .as_deref()
+            .is_some_and(|platform| platform.is_empty())
+        {
+            return Err("--platform needs a value, e.g. linux/arm64".to_string());
+        }
+        if let Some(digest) = reference.split_once('@').map(|(_, digest)| digest)
+            && !digest.contains(':')
+        {
+            return Err(format!("Invalid digest in {}", reference));
+        }
+        Ok((reference, platform))
+    }
+
+    async fn handle_prompt_input(&mut self, key: KeyCode) {
+        let Some((kind, input)) = &mut self.prompt else {
+            return;
+        };
+
+        match key {
+            KeyCode::Esc => {
+                self.prompt = None;
+                self.prompt_error = None;
+            }
+            KeyCode::Enter => {
+                let value = input.value().trim().to_string();
+                match kind {
+                    ImagePrompt::Pull => match Self::parse_pull_args(&value) {
+                        Ok((reference, platform)) => {
+                            self.prompt = None;
+                            self.prompt_error = None;
+                            self.start_pull(reference, platform).await;
+                        }
+                        Err(e) => self.prompt_error = Some(e),
+                    },
+                    ImagePrompt::Push(_) => match Self::validate_tag(&value) {
+                        Ok(()) => {
+                            if let Some((ImagePrompt::Push(image), _)) = self.prompt.take() {
+                                self.prompt_error = None;
+                                self.start_push(&image, value).await;
+                            }
+                        }
+                        Err(e) => self.prompt_error = Some(e),
+                    },
+                    ImagePrompt::Save(images) => {
+                        let images = images.clone();
+                        match self.start_save(&images, &value).await {
+                            Ok(()) => {
+                                self.prompt = None;
+                                self.prompt_error = None;
+                                self.marked.clear();
+                            }
+                            Err(e) => self.prompt_error = Some(e),
+                        }
+                    }
+                    ImagePrompt::Label => {
+                        // Clearing the field removes the filter
+                        self.filters.label = (!value.is_empty()).then_some(value);
+                        self.prompt = None;
+                        self.prompt_error = None;
+                        self.selected_index = 0;
+                        let _ = self.refresh_now().await;
+                    }
+                    ImagePrompt::Load => match self.start_load(&value).await {
+                        Ok(()) => {
+                            self.prompt = None;
+                            self.prompt_error = None;
+                        }
+                        Err(e) => self.prompt_error = Some(e),
+                    },
+                    ImagePrompt::Tag(_) => match Self::validate_tag(&value) {
+                        Ok(()) => {
+                            if let Some((ImagePrompt::Tag(image), _)) = self.prompt.take() {
+                                self.prompt_error = None;
+                                self.tag_image(image, &value).await;
+                            }
+                        }
+                        Err(e) => self.prompt_error = Some(e),
+                    },
+                }
+            }
+            key => {
+                if input.handle_input(key) {
+                    self.prompt_error = None;
+                }
+            }
+        }
+    }
+
+    fn render_prompt(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let Some((kind, input)) = &self.prompt else {
+            return;
+        };
+        let theme = current_theme();
+
+        let (title, label, hint, help) = match kind {
+            ImagePrompt::Pull => (
+                "Pull Image".to_string(),
+                "Image: ",
+                "repo:tag or repo@sha256:..., optionally --platform os/arch",
+                "[Enter] Pull   [Esc] Cancel",
+            ),
+            ImagePrompt::Push(image) => (
+                format!("Push {}", image.display_id),
+                "Push as: ",
+                "One of the image's tags, the registry host comes from its name",
+                "[Enter] Push   [Esc] Cancel",
+            ),
+            ImagePrompt::Save(images) => (
+                match images.as_slice() {
+                    [image] => format!("Save {}", image.repo_tag),
+                    images => format!("Save {} images", images.len()),
+                },
+                "File: ",
+                "Tar archive to create, as docker save",
+                "[Enter] Save   [Esc] Cancel",
+            ),
+            ImagePrompt::Label => (
+                "Filter By Label".to_string(),
+                "Label: ",
+                "key or key=value, empty to show all images",
+                "[Enter] Filter   [Esc] Cancel",
+            ),
+            ImagePrompt::Load => (
+                "Load Images".to_string(),
+                "File: ",
+                "Tar archive created by docker save",
+                "[Enter] Load   [Esc] Cancel",
+            ),
+            ImagePrompt::Tag(image) => (
+                format!("Tag {}", image.display_id),
+                "New tag: ",
+                "[registry[:port]/]repo[:tag], the tag defaults to latest",
+                "[Enter] Tag   [Esc] Cancel",
+            ),
+        };
+
+        let width = (area.width * 2 / 3).max(50).min(area.width);
+        let height = 7.min(area.height);
+        let popup_area = ratatui::layout::Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        // Clear the background
+        f.render_widget(Clear, popup_area);
+
+        let content_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(popup_area);
+
+        let message = match &self.prompt_error {
+            Some(e) => Span::styled(e.clone(), theme.error_style()),
+            None => Span::styled(hint, theme.muted_style()),
+        };
+        let lines = vec![
+            input.line(
+                Span::styled(label, theme.highlight_style()),
+                theme.normal_style(),
+                popup_area.width.saturating_sub(2),
+                true,
+            ),
+            Line::from(""),
+            Line::from(message),
+        ];
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(theme.modal_border_style()),
+        );
+        f.render_widget(paragraph, content_area[0]);
+
+        let help = Paragraph::new(help)
+            .style(theme.muted_style())
+            .alignment(Alignment::Center);
+        f.render_widget(help, content_area[1]);
+    }
+
+    async fn show_history(&mut self, image: &ImageInfo) {
+        let client = self.docker_client.lock().await;
+        match client.image_history(&image.id).await {
+            Ok(layers) => {
+                self.history_view = Some(HistoryView::new(image.repo_tag.clone(), layers));
+            }
+            Err(e) => {
+                self.status_message = Some(StatusMessage::error(format!(
+                    "Failed to get history of {}: {}",
+                    image.repo_tag,
+                    DockerClient::format_error(&e)
+                )));
+            }
+        }
+    }
+
+    fn open_build_dialog(&mut self) {
+        // One build at a time, bring the running one back instead
+        if self
+            .build_view
+            .as_ref()
+            .is_some_and(|view| view.is_running())
+        {
+            self.show_build_view = true;
+            return;
+        }
+        self.build_dialog = Some(BuildDialog::new());
+    }
+
+    /// One pull or push at a time, bring the running one back instead
+    fn reopen_running_transfer(&mut self) -> bool {
+        let running = self
+            .transfer_view
+            .as_ref()
+            .is_some_and(|view| view.is_running());
+        if running {
+            self.show_transfer_view = true;
+        }
+        running
+    }
+
+    async fn start_pull(&mut self, reference: String, platform: Option<String>) {
+        if self.reopen_running_transfer() {
+            return;
+        }
+
+        let client = self.docker_client.lock().await.clone();
+        let stream = client.pull_image(&reference, platform.as_deref());
+        let title = match &platform {
+            Some(platform) => format!("Pull {} ({})", reference, platform),
+            None => format!("Pull {}", reference),
+        };
+        self.transfer_view = Some(ProgressView::start(title, stream, &self.cancellation_token));
+        self.show_transfer_view = true;
+    }
+
+    async fn start_push(&mut self, image: &ImageInfo, reference: String) {
+        if self.reopen_running_transfer() {
+            return;
+        }
+        // The daemon pushes local tags only
+        let normalized = DockerClient::normalize_reference(&reference);
+        if !image.repo_tags.contains(&normalized) {
+            self.status_message = Some(StatusMessage::error(format!(
+                "{} is not a tag of this image, add it with [T] first",
+                normalized
+            )));
+            return;
+        }
+
+        let registry = registry_auth::registry_host(&normalized);
+        let auth = match registry_auth::lookup(&registry).await {
+            Ok(auth) => auth,
+            Err(e) => {
+                self.status_message = Some(StatusMessage::error(format!(
+                    "Failed to read credentials for {}: {}",
+                    registry, e
+                )));
+                return;
+            }
+        };
+
+        if let Some(warning) = auth.warning {
+            self.status_message = Some(StatusMessage::error(warning));
+        }
+
+        let client = self.docker_client.lock().await.clone();
+        let (registry, source) = (auth.registry, auth.source);
+        // Say which credentials were tried when the registry refuses them
+        let stream = client
+            .push_image(&normalized, auth.credentials)
+            .map(move |update| {
+                update.map_err(|e| {
+                    let message = DockerClient::format_error(&e);
+                    if registry_auth::is_auth_error(&message) {
+                        bollard::errors::Error::DockerStreamError {
+                            error: format!(
+                                "Authentication failed for {} (credentials: {}): {}. Run docker login {}",
+                                registry, source, message, registry
+                            ),
+                        }
+                    } else {
+                        e
+                    }
+                })
+            });
+        self.transfer_view = Some(ProgressView::start(
+            format!("Push {}", normalized),
+            stream,
+            &self.cancellation_token,
+        ));
+        self.show_transfer_view = true;
+    }
+
+    fn render_transfer_modal(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let Some(transfer_view) = &self.transfer_view else {
+            return;
+        };
+        let theme = current_theme();
+        let popup_area = crate::ui::centered_rect(80, 70, area);
+
+        // Clear the background
+        f.render_widget(Clear, popup_area);
+
+        let content_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(popup_area);
+
+        transfer_view.render(f, content_area[0]);
+
+        let help = if transfer_view.is_running() {
+            "[↑/↓] Scroll   [H] Hide   [Esc] Cancel"
+        } else {
+            "[↑/↓] Scroll   [Esc] Close"
+        };
+        let help = Paragraph::new(help)
+            .style(theme.muted_style())
+            .alignment(Alignment::Center);
+        f.render_widget(help, content_area[1]);
+    }
+
     async fn inspect_image(&mut self, image: &ImageInfo) -> Result<()> {
         // Show modal immediately with loading state
         self.show_inspect_modal = true;
         self.inspect_data = None;
         self.inspect_scroll = 0;
+        self.json_view = None;
 
         // Fetch inspection data in background
         let client = self.docker_client.lock().await;
@@ -102,14 +1063,51 @@ impl ImagesUI {
         Ok(())
     }
 
+    async fn toggle_json_view(&mut self) {
+        if self.json_view.take().is_some() {
+            return;
+        }
+        let Some(inspect_data) = &self.inspect_data else {
+            return;
+        };
+
+        let client = self.docker_client.lock().await;
+        match client.inspect_image_json(&inspect_data.id).await {
+            Ok(value) => {
+                self.json_view = Some(JsonView::new("Image Inspection (raw JSON)", value));
+            }
+            Err(e) => {
+                self.status_message = Some(StatusMessage::error(format!(
+                    "Failed to inspect image: {}",
+                    DockerClient::format_error(&e)
+                )));
+            }
+        }
+    }
+
+    // "Images (12, dangling, 2 marked)"
+    fn table_title(&self) -> String {
+        let mut parts = vec![self.images.len().to_string()];
+        parts.extend(self.describe_filters());
+        if !self.marked.is_empty() {
+            parts.push(format!("{} marked", self.marked.len()));
+        }
+        format!("Images ({})", parts.join(", "))
+    }
+
     fn render_main_table(&self, f: &mut Frame, area: ratatui::layout::Rect) {
         let theme = current_theme();
 
         if self.images.is_empty() {
-            let paragraph = Paragraph::new("No images found or loading...")
+            let message = if self.describe_filters().is_some() {
+                "No images match the filters"
+            } else {
+                "No images found or loading..."
+            };
+            let paragraph = Paragraph::new(message)
                 .block(
                     Block::default()
-                        .title("Images")
+                        .title(self.table_title())
                         .borders(Borders::ALL)
                         .border_style(theme.border_style()),
                 )
@@ -128,26 +1126,45 @@ impl ImagesUI {
                 Cell::from("Containers").style(theme.header_style()),
             ]);
 
-            // Create table rows using pre-formatted data
+            // Create table rows using pre-formatted data, extra tags below when expanded
             let rows: Vec<Row> = self
                 .images
                 .iter()
                 .enumerate()
-                .map(|(i, image)| {
+                .flat_map(|(i, image)| {
                     let style = if i == self.selected_index {
                         theme.selected_style()
                     } else {
                         theme.normal_style()
                     };
 
-                    Row::new(vec![
-                        Cell::from(image.repo_tag.clone()),
+                    let expanded = self.expanded.contains(&image.id);
+                    let repo_tag = match image.repo_tags.len() {
+                        0 | 1 => image.repo_tag.clone(),
+                        _ if expanded => format!("{} ▾", image.repo_tag),
+                        n => format!("{} (+{})", image.repo_tag, n - 1),
+                    };
+                    let mark = if self.marked.contains(&image.id) {
+                        "● "
+                    } else {
+                        ""
+                    };
+                    let row = Row::new(vec![
+                        Cell::from(format!("{}{}", mark, repo_tag)),
                         Cell::from(image.display_id.clone()),
                         Cell::from(image.size_formatted.clone()),
                         Cell::from(image.created_ago.clone()),
                         Cell::from(image.containers_count.clone()),
                     ])
-                    .style(style)
+                    .style(style);
+
+                    let extra_tags = match image.repo_tags.get(1..) {
+                        Some(tags) if expanded => tags,
+                        _ => &[],
+                    };
+                    std::iter::once(row).chain(extra_tags.iter().map(move |tag| {
+                        Row::new(vec![Cell::from(format!("  ↳ {}", tag))]).style(style)
+                    }))
                 })
                 .collect();
 
@@ -165,7 +1182,7 @@ impl ImagesUI {
             .header(headers)
             .block(
                 Block::default()
-                    .title(format!("Images ({})", self.images.len()))
+                    .title(self.table_title())
                     .borders(Borders::ALL)
                     .border_style(theme.border_style()),
             )
@@ -201,7 +1218,22 @@ impl ImagesUI {
         f.render_widget(Clear, popup_area);
 
         // Render modal content
-        if let Some(inspect_data) = &self.inspect_data {
+        if let Some(json_view) = &self.json_view {
+            let content_area = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(1)])
+                .split(popup_area);
+
+            json_view.render(f, content_area[0]);
+
+            let help = Paragraph::new(
+                "[↑/↓] Move   [Y] Copy Field   [Shift+Y] Copy All   [J] Fields   [Esc] Close",
+            )
+            .style(theme.muted_style())
+            .alignment(Alignment::Center);
+
+            f.render_widget(help, content_area[1]);
+        } else if let Some(inspect_data) = &self.inspect_data {
             let lines = self.format_inspect_data(inspect_data);
 
             // Create scrollable content
@@ -229,7 +1261,7 @@ impl ImagesUI {
             f.render_widget(paragraph, content_area[0]);
 
             // Help text at bottom with theme
-            let help = Paragraph::new("[↑/↓] Scroll   [Esc] Close")
+            let help = Paragraph::new("[↑/↓] Scroll   [J] Raw JSON   [Esc] Close")
                 .style(theme.muted_style())
                 .alignment(Alignment::Center);
 
@@ -250,16 +1282,58 @@ impl ImagesUI {
         }
     }
 
-    fn format_inspect_data<'a>(&self, data: &'a ImageInspectDetails) -> Vec<Line<'a>> {
+    fn render_removal_report(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let Some((target, report)) = &self.removal_report else {
+            return;
+        };
         let theme = current_theme();
-        let mut lines = Vec::new();
+        let popup_area = crate::ui::centered_rect(70, 60, area);
 
-        // Basic Information with theme colors
-        lines.push(Line::from(vec![Span::styled(
-            "Basic Information",
-            theme.header_style(),
-        )]));
-        lines.push(Line::from(""));
+        // Clear the background
+        f.render_widget(Clear, popup_area);
+
+        let content_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(popup_area);
+
+        let lines: Vec<Line> = report
+            .iter()
+            .skip(self.report_scroll)
+            .map(|entry| {
+                let style = if entry.starts_with("Deleted") {
+                    theme.error_style()
+                } else {
+                    theme.warning_style()
+                };
+                Line::from(Span::styled(entry.clone(), style))
+            })
+            .collect();
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .title(format!("Removed {} ({} entries)", target, report.len()))
+                .borders(Borders::ALL)
+                .border_style(theme.modal_border_style()),
+        );
+        f.render_widget(paragraph, content_area[0]);
+
+        let help = Paragraph::new("[↑/↓] Scroll   [Enter/Esc] Close")
+            .style(theme.muted_style())
+            .alignment(Alignment::Center);
+        f.render_widget(help, content_area[1]);
+    }
+
+    fn format_inspect_data<'a>(&self, data: &'a ImageInspectDetails) -> Vec<Line<'a>> {
+        let theme = current_theme();
+        let mut lines = vec![
+            // Basic Information with theme colors
+            Line::from(vec![Span::styled(
+                "Basic Information",
+                theme.header_style(),
+            )]),
+            Line::from(""),
+        ];
 
         lines.push(Line::from(vec![
             Span::styled("ID: ", theme.highlight_style()),
@@ -379,14 +1453,206 @@ impl Component for ImagesUI {
     }
 
     async fn tick(&mut self) {
+        // Pick up finished removals
+        let mut removal_done = false;
+        while let Ok(removal) = self.removal_rx.try_recv() {
+            match removal.result {
+                Ok(report) => {
+                    self.status_message =
+                        Some(StatusMessage::info(format!("Removed {}", removal.target)));
+                    self.removal_report = Some((removal.target, report));
+                    self.report_scroll = 0;
+                }
+                Err(e) => {
+                    self.status_message = Some(StatusMessage::error(format!(
+                        "Failed to remove {}: {}",
+                        removal.target, e
+                    )));
+                }
+            }
+            removal_done = true;
+        }
+        if self
+            .status_message
+            .as_ref()
+            .is_some_and(|message| message.is_expired())
+        {
+            self.status_message = None;
+        }
+
+        // Same for the build, then select the new image
+        let mut build_done = false;
+        if let Some(build_view) = &mut self.build_view {
+            build_done = build_view.poll();
+            if build_done {
+                self.select_after_refresh = build_view.image_id().map(str::to_string);
+            }
+            if !self.show_build_view {
+                self.status_message = Some(build_view.summary());
+                if !build_view.is_running() {
+                    self.build_view = None;
+                }
+            }
+        }
+
+        if let Some(explorer_view) = &mut self.explorer_view {
+            explorer_view.poll();
+        }
+
+        // Follow the pull or push, in the status line while its modal is hidden
+        let mut transfer_done = false;
+        if let Some(transfer_view) = &mut self.transfer_view {
+            transfer_done = transfer_view.poll();
+            if !self.show_transfer_view {
+                self.status_message = Some(transfer_view.summary());
+                if !transfer_view.is_running() {
+                    self.transfer_view = None;
+                }
+            }
+        }
+
         let now = std::time::Instant::now();
-        if now.duration_since(self.last_tick).as_secs() >= 10 {
+        if removal_done
+            || transfer_done
+            || build_done
+            || now.duration_since(self.last_tick).as_secs() >= 10
+        {
             self.last_tick = now;
             let _ = self.refresh_now().await;
         }
     }
 
     async fn handle_input(&mut self, key: KeyCode) -> Result<bool> {
+        if let Some((dialog, _)) = &mut self.delete_dialog {
+            match dialog.handle_input(key) {
+                ConfirmOutcome::Pending => {}
+                ConfirmOutcome::Cancelled => self.delete_dialog = None,
+                ConfirmOutcome::Confirmed => {
+                    if let Some((dialog, image)) = self.delete_dialog.take() {
+                        let untag = dialog.is_checked("untag");
+                        let force = dialog.is_checked("force");
+                        let no_prune = dialog.is_checked("noprune");
+                        self.delete_image(image, untag, force, no_prune).await;
+                    }
+                }
+            }
+            return Ok(true); // Dialog is open, consume all events
+        }
+
+        if let Some(dialog) = &mut self.prune_dialog {
+            match dialog.handle_input(key) {
+                ConfirmOutcome::Pending => {}
+                ConfirmOutcome::Cancelled => self.prune_dialog = None,
+                ConfirmOutcome::Confirmed => {
+                    if let Some(dialog) = self.prune_dialog.take() {
+                        self.prune_images(dialog.is_checked("all")).await;
+                    }
+                }
+            }
+            return Ok(true); // Dialog is open, consume all events
+        }
+
+        if let Some((dialog, _)) = &mut self.untag_dialog {
+            match dialog.handle_input(key) {
+                ConfirmOutcome::Pending => {}
+                ConfirmOutcome::Cancelled => self.untag_dialog = None,
+                ConfirmOutcome::Confirmed => {
+                    if let Some((dialog, image)) = self.untag_dialog.take() {
+                        let tags = dialog.checked().into_iter().map(str::to_string).collect();
+                        self.untag_image(image, tags);
+                    }
+                }
+            }
+            return Ok(true); // Dialog is open, consume all events
+        }
+
+        if self.prompt.is_some() {
+            self.handle_prompt_input(key).await;
+            return Ok(true); // Prompt is open, consume all events
+        }
+
+        if let Some(dialog) = &mut self.build_dialog {
+            match dialog.handle_input(key) {
+                BuildDialogOutcome::Pending => {}
+                BuildDialogOutcome::Cancelled => self.build_dialog = None,
+                BuildDialogOutcome::Build(request) => {
+                    self.build_dialog = None;
+                    let client = self.docker_client.lock().await.clone();
+                    self.build_view =
+                        Some(BuildView::start(request, client, &self.cancellation_token));
+                    self.show_build_view = true;
+                }
+            }
+            return Ok(true); // Dialog is open, consume all events
+        }
+
+        if self.show_build_view
+            && let Some(build_view) = &mut self.build_view
+        {
+            match key {
+                KeyCode::Esc if build_view.is_running() => build_view.cancel(),
+                KeyCode::Esc => {
+                    self.build_view = None;
+                    self.show_build_view = false;
+                }
+                KeyCode::Char('h') if build_view.is_running() => self.show_build_view = false,
+                _ => build_view.handle_input(key),
+            }
+            return Ok(true); // Modal is open, consume all events
+        }
+
+        if self.show_transfer_view
+            && let Some(transfer_view) = &mut self.transfer_view
+        {
+            match key {
+                KeyCode::Esc if transfer_view.is_running() => transfer_view.cancel(),
+                KeyCode::Esc => {
+                    self.transfer_view = None;
+                    self.show_transfer_view = false;
+                }
+                KeyCode::Char('h') if transfer_view.is_running() => self.show_transfer_view = false,
+                _ => transfer_view.handle_input(key),
+            }
+            return Ok(true); // Modal is open, consume all events
+        }
+
+        if let Some(compare_view) = &mut self.compare_view {
+            match key {
+                KeyCode::Esc => self.compare_view = None,
+                _ => compare_view.handle_input(key),
+            }
+            return Ok(true); // Modal is open, consume all events
+        }
+
+        if let Some(explorer_view) = &mut self.explorer_view {
+            match key {
+                KeyCode::Esc => self.explorer_view = None,
+                _ => explorer_view.handle_input(key),
+            }
+            return Ok(true); // Modal is open, consume all events
+        }
+
+        if let Some(history_view) = &mut self.history_view {
+            match key {
+                KeyCode::Esc => self.history_view = None,
+                _ => history_view.handle_input(key),
+            }
+            return Ok(true); // Modal is open, consume all events
+        }
+
+        if let Some((_, report)) = &self.removal_report {
+            match key {
+                KeyCode::Esc | KeyCode::Enter => self.removal_report = None,
+                KeyCode::Up => self.report_scroll = self.report_scroll.saturating_sub(1),
+                KeyCode::Down => {
+                    self.report_scroll =
+                        (self.report_scroll + 1).min(report.len().saturating_sub(1));
+                }
+                _ => {}
+            }
+            return Ok(true); // Report is open, consume all events
+        }
+
         // Handle modal input first
         if self.show_inspect_modal {
             match key {
@@ -394,6 +1660,17 @@ impl Component for ImagesUI {
                     self.show_inspect_modal = false;
                     self.inspect_data = None;
                     self.inspect_scroll = 0;
+                    self.json_view = None;
+                    return Ok(true); // Event handled by modal
+                }
+                KeyCode::Char('j') => {
+                    self.toggle_json_view().await;
+                    return Ok(true); // Event handled by modal
+                }
+                _ if self.json_view.is_some() => {
+                    if let Some(json_view) = &mut self.json_view {
+                        json_view.handle_input(key);
+                    }
                     return Ok(true); // Event handled by modal
                 }
                 KeyCode::Up => {
@@ -439,14 +1716,105 @@ impl Component for ImagesUI {
             KeyCode::Char('d') => {
                 if let Some(image) = self.get_selected_image() {
                     let image = image.clone();
-                    self.delete_image(&image).await?;
+                    self.confirm_delete_image(image).await;
                 }
                 Ok(true) // Event handled
             }
             KeyCode::Char('p') => {
+                self.open_prompt(ImagePrompt::Pull);
+                Ok(true) // Event handled
+            }
+            KeyCode::Char('t') => {
                 if let Some(image) = self.get_selected_image() {
                     let image = image.clone();
-                    self.pull_image(&image).await?;
+                    self.open_prompt(ImagePrompt::Tag(image));
+                }
+                Ok(true) // Event handled
+            }
+            KeyCode::Char('P') => {
+                if let Some(image) = self.get_selected_image() {
+                    if image.repo_tags.is_empty() {
+                        self.status_message =
+                            Some(StatusMessage::error("Cannot push image without tag"));
+                    } else {
+                        let image = image.clone();
+                        self.open_prompt(ImagePrompt::Push(image));
+                    }
+                }
+                Ok(true) // Event handled
+            }
+            KeyCode::Char(' ') => {
+                self.toggle_mark();
+                Ok(true) // Event handled
+            }
+            KeyCode::Char('s') => {
+                self.open_save_prompt();
+                Ok(true) // Event handled
+            }
+            KeyCode::Char('l') => {
+                self.open_load_prompt();
+                Ok(true) // Event handled
+            }
+            KeyCode::Char('u') => {
+                if let Some(image) = self.get_selected_image() {
+                    let image = image.clone();
+                    self.confirm_untag_image(image);
+                }
+                Ok(true) // Event handled
+            }
+            KeyCode::Enter => {
+                if let Some(image) = self.get_selected_image()
+                    && image.repo_tags.len() > 1
+                {
+                    let id = image.id.clone();
+                    if !self.expanded.remove(&id) {
+                        self.expanded.insert(id);
+                    }
+                }
+                Ok(true) // Event handled
+            }
+            KeyCode::Char('D') => {
+                self.toggle_dangling_filter();
+                self.refresh_now().await?;
+                Ok(true) // Event handled
+            }
+            KeyCode::Char('U') => {
+                self.toggle_unused_filter();
+                self.refresh_now().await?;
+                Ok(true) // Event handled
+            }
+            KeyCode::Char('L') => {
+                self.open_label_prompt();
+                Ok(true) // Event handled
+            }
+            KeyCode::Char('x') => {
+                self.confirm_prune().await;
+                Ok(true) // Event handled
+            }
+            KeyCode::Char('b') => {
+                self.open_build_dialog();
+                Ok(true) // Event handled
+            }
+            KeyCode::Char('h') => {
+                if let Some(image) = self.get_selected_image() {
+                    let image = image.clone();
+                    self.show_history(&image).await;
+                }
+                Ok(true) // Event handled
+            }
+            KeyCode::Char('c') => {
+                self.compare_marked().await;
+                Ok(true) // Event handled
+            }
+            KeyCode::Char('e') => {
+                if let Some(image) = self.get_selected_image() {
+                    let image = image.clone();
+                    let client = self.docker_client.lock().await.clone();
+                    self.explorer_view = Some(ExplorerView::start(
+                        &image,
+                        client,
+                        &self.cancellation_token,
+                    ));
                 }
                 Ok(true) // Event handled
             }
@@ -462,20 +1830,109 @@ impl Component for ImagesUI {
     }
 
     fn render(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        // Reserve a line for the status message if there is one
+        let table_area = if let Some(message) = &self.status_message {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(1)])
+                .split(area);
+
+            let status = Paragraph::new(message.text.clone()).style(message.style());
+            f.render_widget(status, chunks[1]);
+            chunks[0]
+        } else {
+            area
+        };
+
         // Render main table
-        self.render_main_table(f, area);
+        self.render_main_table(f, table_area);
 
         // Render modal if active
         if self.show_inspect_modal {
             self.render_inspect_modal(f, area);
         }
+        if let Some(history_view) = &self.history_view {
+            history_view.render(f, area);
+        }
+        if let Some(explorer_view) = &self.explorer_view {
+            explorer_view.render(f, area);
+        }
+        if let Some(compare_view) = &self.compare_view {
+            compare_view.render(f, area);
+        }
+        if let Some((dialog, _)) = &self.delete_dialog {
+            dialog.render(f, area);
+        }
+        if self.removal_report.is_some() {
+            self.render_removal_report(f, area);
+        }
+        if self.show_transfer_view {
+            self.render_transfer_modal(f, area);
+        }
+        if self.prompt.is_some() {
+            self.render_prompt(f, area);
+        }
+        if let Some((dialog, _)) = &self.untag_dialog {
+            dialog.render(f, area);
+        }
+        if let Some(dialog) = &self.prune_dialog {
+            dialog.render(f, area);
+        }
+        if self.show_build_view
+            && let Some(build_view) = &self.build_view
+        {
+            build_view.render(f, area);
+        }
+        if let Some(dialog) = &self.build_dialog {
+            dialog.render(f, area);
+        }
     }
 
     fn render_help(&self) -> &'static str {
-        if self.show_inspect_modal {
+        if let Some((dialog, _)) = &self.delete_dialog {
+            dialog.render_help()
+        } else if let Some(dialog) = &self.build_dialog {
+            dialog.render_help()
+        } else if self.show_build_view
+            && let Some(build_view) = &self.build_view
+        {
+            build_view.render_help()
+        } else if let Some((dialog, _)) = &self.untag_dialog {
+            dialog.render_help()
+        } else if let Some(dialog) = &self.prune_dialog {
+            dialog.render_help()
+        } else if let Some((ImagePrompt::Tag(_), _)) = &self.prompt {
+            "Type new tag   [Enter] Tag   [Esc] Cancel"
+        } else if let Some((ImagePrompt::Push(_), _)) = &self.prompt {
+            "Type tag to push   [Enter] Push   [Esc] Cancel"
+        } else if let Some((ImagePrompt::Save(_), _)) = &self.prompt {
+            "Type archive path   [Enter] Save   [Esc] Cancel"
+        } else if let Some((ImagePrompt::Load, _)) = &self.prompt {
+            "Type archive path   [Enter] Load   [Esc] Cancel"
+        } else if let Some((ImagePrompt::Label, _)) = &self.prompt {
+            "Type key or key=value, empty to clear   [Enter] Filter   [Esc] Cancel"
+        } else if self.prompt.is_some() {
+            "Type image reference   [Enter] Pull   [Esc] Cancel"
+        } else if self.show_transfer_view
+            && self.transfer_view.as_ref().is_some_and(|v| v.is_running())
+        {
+            "[↑/↓] Scroll   [H] Hide   [Esc] Cancel"
+        } else if self.show_transfer_view {
             "[↑/↓] Scroll   [Esc] Close"
+        } else if let Some(compare_view) = &self.compare_view {
+            compare_view.render_help()
+        } else if let Some(explorer_view) = &self.explorer_view {
+            explorer_view.render_help()
+        } else if let Some(history_view) = &self.history_view {
+            history_view.render_help()
+        } else if self.removal_report.is_some() {
+            "[↑/↓] Scroll   [Enter/Esc] Close"
+        } else if self.json_view.is_some() {
+            "[↑/↓/PgUp/PgDn] Move   [Y] Copy Field   [Shift+Y] Copy All   [J] Fields   [Esc] Close"
+        } else if self.show_inspect_modal {
+            "[↑/↓] Scroll   [J] Raw JSON   [Esc] Close"
         } else {
-            "[↑/↓] Select   [D] Delete   [P] Pull   [I] Inspect   [R/F5] Refresh   [Q] Quit"
+            "[↑/↓] Select   [Space] Mark   [C] Compare Marked   [Enter] Expand Tags   [D] Delete   [P] Pull   [Shift+P] Push   [B] Build   [T] Tag   [U] Untag   [S] Save   [L] Load   [X] Prune   [Shift+D/U/L] Filter Dangling/Unused/Label   [I] Inspect   [H] History   [E] Explore Layers   [R/F5] Refresh   [Q] Quit"
         }
     }
 }
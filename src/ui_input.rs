@@ -0,0 +1,125 @@
+use crate::theme::current_theme;
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+use std::path::PathBuf;
+
+/// Path typed in a prompt, with `~/` expanded like a shell would
+pub fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Single-line editable text with a cursor, for prompts and dialog fields
+#[derive(Debug, Clone, Default)]
+pub struct TextInput {
+    value: String,
+    // Cursor position in chars, not bytes
+    cursor: usize,
+}
+
+impl TextInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_value(value: impl Into<String>) -> Self {
+        let value = value.into();
+        Self {
+            cursor: value.chars().count(),
+            value,
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    fn byte_index(&self, cursor: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(cursor)
+            .map(|(index, _)| index)
+            .unwrap_or(self.value.len())
+    }
+
+    /// Edit and cursor keys, returns true when the value changed
+    pub fn handle_input(&mut self, key: KeyCode) -> bool {
+        let len = self.value.chars().count();
+        match key {
+            KeyCode::Char(c) => {
+                let index = self.byte_index(self.cursor);
+                self.value.insert(index, c);
+                self.cursor += 1;
+                true
+            }
+            KeyCode::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                let index = self.byte_index(self.cursor);
+                self.value.remove(index);
+                true
+            }
+            KeyCode::Delete if self.cursor < len => {
+                let index = self.byte_index(self.cursor);
+                self.value.remove(index);
+                true
+            }
+            KeyCode::Left => {
+                self.cursor = self.cursor.saturating_sub(1);
+                false
+            }
+            KeyCode::Right => {
+                self.cursor = (self.cursor + 1).min(len);
+                false
+            }
+            KeyCode::Home => {
+                self.cursor = 0;
+                false
+            }
+            KeyCode::End => {
+                self.cursor = len;
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// The prompt followed by the value, scrolled so the cursor fits in `width`
+    pub fn line(
+        &self,
+        prompt: Span<'static>,
+        style: Style,
+        width: u16,
+        focused: bool,
+    ) -> Line<'static> {
+        let theme = current_theme();
+        let available = (width as usize).saturating_sub(prompt.width() + 1).max(1);
+
+        // The cursor is always within [start, end]
+        let chars: Vec<char> = self.value.chars().collect();
+        let start = (self.cursor + 1).saturating_sub(available);
+        let end = (start + available).min(chars.len());
+        let before: String = chars[start..self.cursor].iter().collect();
+
+        let mut spans = vec![prompt, Span::styled(before, style)];
+        let after_start = if focused {
+            let at_cursor = chars.get(self.cursor).copied().unwrap_or(' ');
+            spans.push(Span::styled(
+                at_cursor.to_string(),
+                theme.normal_style().add_modifier(Modifier::REVERSED),
+            ));
+            (self.cursor + 1).min(end)
+        } else {
+            self.cursor
+        };
+        let after: String = chars[after_start..end].iter().collect();
+        spans.push(Span::styled(after, style));
+
+        Line::from(spans)
+    }
+}
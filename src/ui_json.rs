@@ -0,0 +1,240 @@
+use crate::components::StatusMessage;
+use crate::theme::current_theme;
+
+use base64::Engine;
+use crossterm::event::KeyCode;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+use serde_json::Value;
+use std::io::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const INDENT: &str = "  ";
+
+// Key (objects only), value and pointer segment of each member of a container
+type Members<'a> = Vec<(Option<&'a str>, &'a Value, String)>;
+
+struct JsonLine {
+    spans: Vec<Span<'static>>,
+    // JSON pointer of the value on this line, used for copying
+    pointer: String,
+}
+
+/// Pretty-printed raw inspect result with a line cursor, shared by the inspect modals
+pub struct JsonView {
+    title: String,
+    value: Value,
+    lines: Vec<JsonLine>,
+    selected: usize,
+    scroll: usize,
+    // Content height seen by the last render, used for paging
+    page_height: AtomicUsize,
+    copy_message: Option<StatusMessage>,
+}
+
+impl JsonView {
+    pub fn new(title: impl Into<String>, value: Value) -> Self {
+        let mut lines = Vec::new();
+        Self::push_value(&mut lines, None, &value, String::new(), 0, false);
+
+        Self {
+            title: title.into(),
+            value,
+            lines,
+            selected: 0,
+            scroll: 0,
+            page_height: AtomicUsize::new(20),
+            copy_message: None,
+        }
+    }
+
+    fn push_value(
+        lines: &mut Vec<JsonLine>,
+        key: Option<&str>,
+        value: &Value,
+        pointer: String,
+        depth: usize,
+        comma: bool,
+    ) {
+        let theme = current_theme();
+        let punctuation = theme.muted_style();
+
+        let mut prefix = vec![Span::raw(INDENT.repeat(depth))];
+        if let Some(key) = key {
+            prefix.push(Span::styled(
+                serde_json::to_string(key).unwrap_or_default(),
+                theme.highlight_style(),
+            ));
+            prefix.push(Span::styled(": ", punctuation));
+        }
+        let comma = if comma { "," } else { "" };
+
+        let (open, close, children): (&str, &str, Members) = match value {
+            Value::Object(map) if !map.is_empty() => (
+                "{",
+                "}",
+                map.iter()
+                    .map(|(k, v)| (Some(k.as_str()), v, Self::escape_pointer(k)))
+                    .collect(),
+            ),
+            Value::Array(items) if !items.is_empty() => (
+                "[",
+                "]",
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| (None, v, i.to_string()))
+                    .collect(),
+            ),
+            _ => {
+                let style = match value {
+                    Value::String(_) => theme.success_style(),
+                    Value::Number(_) => theme.info_style(),
+                    Value::Bool(_) | Value::Null => theme.warning_style(),
+                    _ => punctuation,
+                };
+                prefix.push(Span::styled(value.to_string(), style));
+                prefix.push(Span::styled(comma, punctuation));
+                lines.push(JsonLine {
+                    spans: prefix,
+                    pointer,
+                });
+                return;
+            }
+        };
+
+        prefix.push(Span::styled(open, punctuation));
+        lines.push(JsonLine {
+            spans: prefix,
+            pointer: pointer.clone(),
+        });
+
+        let last = children.len() - 1;
+        for (i, (child_key, child, segment)) in children.into_iter().enumerate() {
+            let child_pointer = format!("{}/{}", pointer, segment);
+            Self::push_value(lines, child_key, child, child_pointer, depth + 1, i < last);
+        }
+
+        lines.push(JsonLine {
+            spans: vec![
+                Span::raw(INDENT.repeat(depth)),
+                Span::styled(format!("{}{}", close, comma), punctuation),
+            ],
+            pointer,
+        });
+    }
+
+    fn escape_pointer(key: &str) -> String {
+        key.replace('~', "~0").replace('/', "~1")
+    }
+
+    /// Handle navigation and copy keys, returns false for keys left to the caller
+    pub fn handle_input(&mut self, key: KeyCode) -> bool {
+        let page = self.page_height.load(Ordering::Relaxed).max(1);
+        let last = self.lines.len().saturating_sub(1);
+
+        match key {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => self.selected = (self.selected + 1).min(last),
+            KeyCode::PageUp => self.selected = self.selected.saturating_sub(page),
+            KeyCode::PageDown => self.selected = (self.selected + page).min(last),
+            KeyCode::Home => self.selected = 0,
+            KeyCode::End => self.selected = last,
+            KeyCode::Char('y') => self.copy_selected(),
+            KeyCode::Char('Y') => {
+                let text = serde_json::to_string_pretty(&self.value).unwrap_or_default();
+                self.copy(&text, "Copied full JSON");
+            }
+            _ => return false,
+        }
+
+        // Keep the cursor on screen
+        if self.selected < self.scroll {
+            self.scroll = self.selected;
+        } else if self.selected >= self.scroll + page {
+            self.scroll = self.selected + 1 - page;
+        }
+        true
+    }
+
+    fn copy_selected(&mut self) {
+        let Some(line) = self.lines.get(self.selected) else {
+            return;
+        };
+        let Some(value) = self.value.pointer(&line.pointer) else {
+            return;
+        };
+
+        // Strings are copied without quotes, everything else as JSON
+        let text = match value {
+            Value::String(s) => s.clone(),
+            other => serde_json::to_string_pretty(other).unwrap_or_default(),
+        };
+        let what = if line.pointer.is_empty() {
+            "Copied full JSON".to_string()
+        } else {
+            format!("Copied {}", line.pointer)
+        };
+        self.copy(&text, &what);
+    }
+
+    fn copy(&mut self, text: &str, what: &str) {
+        self.copy_message = Some(match copy_to_clipboard(text) {
+            Ok(()) => StatusMessage::info(what),
+            Err(e) => StatusMessage::error(format!("Copy failed: {}", e)),
+        });
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let theme = current_theme();
+
+        let mut block = Block::default()
+            .title(self.title.clone())
+            .borders(Borders::ALL)
+            .border_style(theme.modal_border_style());
+        if let Some(message) = self
+            .copy_message
+            .as_ref()
+            .filter(|message| !message.is_expired())
+        {
+            block =
+                block.title_bottom(Line::styled(format!(" {} ", message.text), message.style()));
+        }
+
+        let height = block.inner(area).height as usize;
+        self.page_height.store(height, Ordering::Relaxed);
+
+        let visible_lines: Vec<Line> = self
+            .lines
+            .iter()
+            .enumerate()
+            .skip(self.scroll)
+            .take(height)
+            .map(|(i, line)| {
+                let line = Line::from(line.spans.clone());
+                if i == self.selected {
+                    line.patch_style(theme.selected_style())
+                } else {
+                    line
+                }
+            })
+            .collect();
+
+        let paragraph = Paragraph::new(visible_lines)
+            .block(block)
+            .style(theme.normal_style());
+        f.render_widget(paragraph, area);
+    }
+}
+
+/// Copy through the terminal with OSC 52 so it also works over SSH
+pub fn copy_to_clipboard(text: &str) -> std::io::Result<()> {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", encoded)?;
+    stdout.flush()
+}
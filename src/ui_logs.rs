@@ -0,0 +1,967 @@
+use crate::components::StatusMessage;
+use crate::docker::{ContainerInfo, DockerClient, LogDecoder, LogLine, LogSource};
+use crate::theme::current_theme;
+use crate::ui::{sanitize, strip_ansi};
+use crate::ui_input::{TextInput, expand_home};
+
+use crossterm::event::KeyCode;
+use futures::StreamExt;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+use regex::{Regex, RegexBuilder};
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+// Oldest lines are dropped once the buffer exceeds either limit
+const MAX_LOG_LINES: usize = 200_000;
+const MAX_LOG_BYTES: usize = 32 * 1024 * 1024;
+
+// Lines moved by [PgUp]/[PgDn]
+const PAGE_SCROLL: usize = 20;
+
+// `--tail` presets, cycled with [L]
+const TAIL_PRESETS: &[&str] = &["1000", "10000", "all", "100"];
+
+// `--since` presets in seconds (0 = no limit), cycled with [S]
+const SINCE_PRESETS: &[(i64, &str)] = &[(0, "all"), (600, "10m"), (3600, "1h"), (86400, "24h")];
+
+/// Options of the save dialog, opened with [E]
+struct SaveDialog {
+    path: TextInput,
+    focus: usize,
+    full_history: bool,
+    strip_ansi: bool,
+    timestamps: bool,
+    // Shown in place of the options hint, e.g. the file exists
+    error: Option<String>,
+}
+
+impl SaveDialog {
+    // Path field plus one checkbox per option
+    const FIELDS: usize = 4;
+
+    fn new(container_name: &str, timestamps: bool) -> Self {
+        Self {
+            path: TextInput::with_value(format!(
+                "{}-{}.log",
+                container_name,
+                chrono::Local::now().format("%Y%m%d-%H%M%S")
+            )),
+            focus: 0,
+            full_history: false,
+            strip_ansi: true,
+            timestamps,
+            error: None,
+        }
+    }
+
+    fn toggle_focused(&mut self) {
+        match self.focus {
+            1 => self.full_history = !self.full_history,
+            2 => self.strip_ansi = !self.strip_ansi,
+            3 => self.timestamps = !self.timestamps,
+            _ => {}
+        }
+    }
+}
+
+enum LogEvent {
+    Lines(Vec<LogLine>),
+    Ended,
+    Error(String),
+}
+
+/// Log line as displayed and searched, sanitized once on arrival. The
+/// original message is kept only when it differs, for saving
+struct BufferedLine {
+    line: LogLine,
+    raw: Option<String>,
+}
+
+impl BufferedLine {
+    fn new(mut line: LogLine) -> Self {
+        let raw = match sanitize(&line.message) {
+            Cow::Owned(clean) => Some(std::mem::replace(&mut line.message, clean)),
+            Cow::Borrowed(_) => None,
+        };
+        Self { line, raw }
+    }
+
+    fn size(&self) -> usize {
+        self.line.message.len() + self.raw.as_ref().map_or(0, String::len)
+    }
+
+    /// The line as the container wrote it
+    fn original(&self) -> LogLine {
+        match &self.raw {
+            Some(raw) => LogLine {
+                source: self.line.source,
+                timestamp: self.line.timestamp.clone(),
+                message: raw.clone(),
+            },
+            None => self.line.clone(),
+        }
+    }
+}
+
+/// Ring buffer of log lines bounded by line count and message bytes.
+/// Lines keep an absolute index so search matches survive eviction.
+struct LogBuffer {
+    lines: VecDeque<BufferedLine>,
+    bytes: usize,
+    first_index: usize,
+}
+
+impl LogBuffer {
+    fn new() -> Self {
+        Self {
+            lines: VecDeque::new(),
+            bytes: 0,
+            first_index: 0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Absolute index one past the newest line
+    fn end_index(&self) -> usize {
+        self.first_index + self.lines.len()
+    }
+
+    fn get(&self, index: usize) -> Option<&LogLine> {
+        index
+            .checked_sub(self.first_index)
+            .and_then(|i| self.lines.get(i))
+            .map(|buffered| &buffered.line)
+    }
+
+    fn push(&mut self, line: LogLine) {
+        let line = BufferedLine::new(line);
+        self.bytes += line.size();
+        self.lines.push_back(line);
+
+        while self.lines.len() > MAX_LOG_LINES || self.bytes > MAX_LOG_BYTES {
+            match self.lines.pop_front() {
+                Some(dropped) => {
+                    self.bytes -= dropped.size();
+                    self.first_index += 1;
+                }
+                None => break,
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.first_index = self.end_index();
+        self.lines.clear();
+        self.bytes = 0;
+    }
+}
+
+pub struct LogViewer {
+    client: DockerClient,
+    container_id: String,
+    container_name: String,
+    buffer: LogBuffer,
+    // Stream state
+    event_rx: mpsc::UnboundedReceiver<LogEvent>,
+    stream_token: CancellationToken,
+    parent_token: CancellationToken,
+    stream_status: Option<String>,
+    // View options
+    follow: bool,
+    show_timestamps: bool,
+    wrap: bool,
+    tail_index: usize,
+    since_index: usize,
+    // Displayed lines between the bottom of the view and the newest one
+    scroll_offset: usize,
+    // Search state, `matches` holds absolute buffer indices in order
+    search_input: Option<TextInput>,
+    search_query: String,
+    search_regex: Option<Regex>,
+    search_error: Option<String>,
+    matches: VecDeque<usize>,
+    current_match: Option<usize>,
+    filter_mode: bool,
+    // Save to file
+    save_dialog: Option<SaveDialog>,
+    save_tx: mpsc::UnboundedSender<StatusMessage>,
+    save_rx: mpsc::UnboundedReceiver<StatusMessage>,
+    status_message: Option<StatusMessage>,
+}
+
+impl LogViewer {
+    pub fn open(
+        client: DockerClient,
+        container: &ContainerInfo,
+        cancellation_token: &CancellationToken,
+    ) -> Self {
+        let (_, event_rx) = mpsc::unbounded_channel();
+        let (save_tx, save_rx) = mpsc::unbounded_channel();
+        let mut viewer = Self {
+            client,
+            container_id: container.id.clone(),
+            container_name: container.name.clone(),
+            buffer: LogBuffer::new(),
+            event_rx,
+            stream_token: cancellation_token.child_token(),
+            parent_token: cancellation_token.clone(),
+            stream_status: None,
+            follow: true,
+            show_timestamps: false,
+            wrap: true,
+            tail_index: 0,
+            since_index: 0,
+            scroll_offset: 0,
+            search_input: None,
+            search_query: String::new(),
+            search_regex: None,
+            search_error: None,
+            matches: VecDeque::new(),
+            current_match: None,
+            filter_mode: false,
+            save_dialog: None,
+            save_tx,
+            save_rx,
+            status_message: None,
+        };
+        viewer.start_stream();
+        viewer
+    }
+
+    /// (Re)start the background log stream with the current limits
+    fn start_stream(&mut self) {
+        self.stream_token.cancel();
+        self.stream_token = self.parent_token.child_token();
+        self.buffer.clear();
+        self.matches.clear();
+        self.current_match = None;
+        self.scroll_offset = 0;
+        self.stream_status = None;
+
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        self.event_rx = event_rx;
+
+        let client = self.client.clone();
+        let container_id = self.container_id.clone();
+        let tail = TAIL_PRESETS[self.tail_index].to_string();
+        // The logs API takes an i32 timestamp
+        let since = match SINCE_PRESETS[self.since_index].0 {
+            0 => Ok(0),
+            secs => i32::try_from(chrono::Utc::now().timestamp() - secs),
+        };
+        let Ok(since) = since else {
+            self.stream_status = Some("error: --since timestamp out of range".to_string());
+            return;
+        };
+        let token = self.stream_token.clone();
+
+        tokio::spawn(async move {
+            let mut stream = client.stream_logs(&container_id, true, since, &tail);
+            let mut decoder = LogDecoder::default();
+
+            loop {
+                tokio::select! {
+                    _ = token.cancelled() => break,
+                    item = stream.next() => {
+                        let event = match item {
+                            Some(Ok(output)) => LogEvent::Lines(decoder.decode(&output)),
+                            Some(Err(e)) => LogEvent::Error(e.to_string()),
+                            None => {
+                                let _ = event_tx.send(LogEvent::Lines(decoder.finish()));
+                                LogEvent::Ended
+                            }
+                        };
+                        let done = !matches!(event, LogEvent::Lines(_));
+                        if event_tx.send(event).is_err() || done {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    /// Move received lines into the buffer, called from the component tick
+    pub fn poll(&mut self) {
+        while let Ok(message) = self.save_rx.try_recv() {
+            self.status_message = Some(message);
+        }
+        if self
+            .status_message
+            .as_ref()
+            .is_some_and(|message| message.is_expired())
+        {
+            self.status_message = None;
+        }
+
+        while let Ok(event) = self.event_rx.try_recv() {
+            match event {
+                LogEvent::Lines(lines) => {
+                    for line in lines {
+                        let index = self.buffer.end_index();
+                        let is_match = self.is_match(&line);
+                        self.buffer.push(line);
+
+                        if is_match {
+                            self.matches.push_back(index);
+                        }
+                        // Keep the view still while follow is paused
+                        if !self.follow && (!self.filter_mode || is_match) {
+                            self.scroll_offset += 1;
+                        }
+                    }
+
+                    // Forget matches that were evicted from the buffer
+                    while self
+                        .matches
+                        .front()
+                        .is_some_and(|&index| index < self.buffer.first_index)
+                    {
+                        self.matches.pop_front();
+                    }
+                    if self
+                        .current_match
+                        .is_some_and(|index| index < self.buffer.first_index)
+                    {
+                        self.current_match = None;
+                    }
+                    self.scroll_offset = self
+                        .scroll_offset
+                        .min(self.displayed_len().saturating_sub(1));
+                }
+                LogEvent::Ended => {
+                    self.stream_status = Some("stream ended".to_string());
+                }
+                LogEvent::Error(e) => {
+                    self.stream_status = Some(format!("error: {}", e));
+                }
+            }
+        }
+    }
+
+    pub fn close(&mut self) {
+        self.stream_token.cancel();
+    }
+
+    fn is_match(&self, line: &LogLine) -> bool {
+        self.search_regex
+            .as_ref()
+            .is_some_and(|regex| regex.is_match(&line.message))
+    }
+
+    fn displayed_len(&self) -> usize {
+        if self.filter_mode {
+            self.matches.len()
+        } else {
+            self.buffer.len()
+        }
+    }
+
+    /// Recompile the search and rescan the buffer
+    fn update_search(&mut self, query: &str) {
+        self.search_query = query.to_string();
+        self.matches.clear();
+        self.current_match = None;
+
+        if query.is_empty() {
+            self.search_regex = None;
+            self.search_error = None;
+        } else {
+            // Smart case: only case sensitive when the query has uppercase
+            let case_insensitive = !query.chars().any(char::is_uppercase);
+            match RegexBuilder::new(query)
+                .case_insensitive(case_insensitive)
+                .build()
+            {
+                Ok(regex) => {
+                    self.search_regex = Some(regex);
+                    self.search_error = None;
+                }
+                Err(_) => {
+                    self.search_regex = None;
+                    self.search_error = Some("invalid regex".to_string());
+                }
+            }
+        }
+
+        if let Some(regex) = &self.search_regex {
+            self.matches = (self.buffer.first_index..self.buffer.end_index())
+                .filter(|&index| {
+                    self.buffer
+                        .get(index)
+                        .is_some_and(|line| regex.is_match(&line.message))
+                })
+                .collect();
+        }
+
+        if self.filter_mode {
+            self.scroll_offset = 0;
+        } else {
+            // Jump to the last match above the bottom of the view
+            self.jump_to_match(false);
+        }
+    }
+
+    /// Absolute index of the line currently at the bottom of the view
+    fn bottom_index(&self) -> Option<usize> {
+        let position = self.displayed_len().checked_sub(self.scroll_offset + 1)?;
+        if self.filter_mode {
+            self.matches.get(position).copied()
+        } else {
+            Some(self.buffer.first_index + position)
+        }
+    }
+
+    /// Move to the next (forward) or previous match, wrapping around
+    fn jump_to_match(&mut self, forward: bool) {
+        if self.matches.is_empty() {
+            return;
+        }
+
+        let from = self
+            .current_match
+            .or_else(|| {
+                self.bottom_index()
+                    .map(|index| index + usize::from(!forward))
+            })
+            .unwrap_or(0);
+
+        let target = if forward {
+            self.matches
+                .iter()
+                .copied()
+                .find(|&index| index > from)
+                .unwrap_or(self.matches[0])
+        } else {
+            self.matches
+                .iter()
+                .rev()
+                .copied()
+                .find(|&index| index < from)
+                .unwrap_or(self.matches[self.matches.len() - 1])
+        };
+
+        self.current_match = Some(target);
+        self.follow = false;
+
+        // Scroll so the match sits in the middle of a page
+        let position = if self.filter_mode {
+            self.matches
+                .iter()
+                .position(|&index| index == target)
+                .unwrap_or(0)
+        } else {
+            target - self.buffer.first_index
+        };
+        let from_bottom = self.displayed_len().saturating_sub(position + 1);
+        self.scroll_offset = from_bottom.saturating_sub(PAGE_SCROLL / 2);
+    }
+
+    /// Write buffered or full logs to the chosen path in background.
+    /// The full history is streamed from the daemon straight to the file
+    fn save_logs(&mut self, dialog: SaveDialog, path: PathBuf) {
+        let path = path.display().to_string();
+
+        let buffered: Option<Vec<LogLine>> = if dialog.full_history {
+            None
+        } else {
+            Some(
+                self.buffer
+                    .lines
+                    .iter()
+                    .map(BufferedLine::original)
+                    .collect(),
+            )
+        };
+
+        self.status_message = Some(StatusMessage::info(format!("Saving logs to {}...", path)));
+
+        let client = self.client.clone();
+        let container_id = self.container_id.clone();
+        let save_tx = self.save_tx.clone();
+        let (timestamps, strip) = (dialog.timestamps, dialog.strip_ansi);
+
+        tokio::spawn(async move {
+            let message = match write_logs(
+                &client,
+                &container_id,
+                buffered,
+                &path,
+                timestamps,
+                strip,
+            )
+            .await
+            {
+                Ok(count) => StatusMessage::info(format!("Saved {} lines to {}", count, path)),
+                Err(e) => StatusMessage::error(e),
+            };
+            let _ = save_tx.send(message);
+        });
+    }
+
+    fn handle_save_input(&mut self, key: KeyCode) {
+        let Some(dialog) = &mut self.save_dialog else {
+            return;
+        };
+
+        match key {
+            KeyCode::Esc => {
+                self.save_dialog = None;
+            }
+            KeyCode::Enter => {
+                // The same trimmed path is checked and written
+                let value = dialog.path.value().trim();
+                let path = expand_home(value);
+                if value.is_empty() {
+                    dialog.error = Some("No file path given".to_string());
+                } else if path.exists() {
+                    dialog.error = Some(format!("{} already exists", path.display()));
+                } else if let Some(dialog) = self.save_dialog.take() {
+                    self.save_logs(dialog, path);
+                }
+            }
+            KeyCode::Up | KeyCode::BackTab => {
+                dialog.focus = (dialog.focus + SaveDialog::FIELDS - 1) % SaveDialog::FIELDS;
+            }
+            KeyCode::Down | KeyCode::Tab => {
+                dialog.focus = (dialog.focus + 1) % SaveDialog::FIELDS;
+            }
+            key if dialog.focus == 0 => {
+                dialog.path.handle_input(key);
+                dialog.error = None;
+            }
+            KeyCode::Char(' ') => {
+                dialog.toggle_focused();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_search_input(&mut self, key: KeyCode) {
+        let Some(input) = &mut self.search_input else {
+            return;
+        };
+
+        match key {
+            KeyCode::Esc => {
+                self.search_input = None;
+                self.update_search("");
+            }
+            KeyCode::Enter => {
+                self.search_input = None;
+            }
+            key => {
+                if input.handle_input(key) {
+                    let query = input.value().to_string();
+                    self.update_search(&query);
+                }
+            }
+        }
+    }
+
+    /// Returns false when the viewer should be closed
+    pub fn handle_input(&mut self, key: KeyCode) -> bool {
+        if self.save_dialog.is_some() {
+            self.handle_save_input(key);
+            return true;
+        }
+        if self.search_input.is_some() {
+            self.handle_search_input(key);
+            return true;
+        }
+
+        let max_offset = self.displayed_len().saturating_sub(1);
+        match key {
+            KeyCode::Esc => {
+                self.close();
+                return false;
+            }
+            KeyCode::Up => {
+                self.follow = false;
+                self.scroll_offset = (self.scroll_offset + 1).min(max_offset);
+            }
+            KeyCode::Down => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+            }
+            KeyCode::PageUp => {
+                self.follow = false;
+                self.scroll_offset = (self.scroll_offset + PAGE_SCROLL).min(max_offset);
+            }
+            KeyCode::PageDown => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(PAGE_SCROLL);
+            }
+            KeyCode::Home => {
+                self.follow = false;
+                self.scroll_offset = max_offset;
+            }
+            KeyCode::End => {
+                self.follow = true;
+                self.scroll_offset = 0;
+            }
+            KeyCode::Char('f') => {
+                self.follow = !self.follow;
+                if self.follow {
+                    self.scroll_offset = 0;
+                }
+            }
+            KeyCode::Char('t') => {
+                self.show_timestamps = !self.show_timestamps;
+            }
+            KeyCode::Char('w') => {
+                self.wrap = !self.wrap;
+            }
+            KeyCode::Char('l') => {
+                self.tail_index = (self.tail_index + 1) % TAIL_PRESETS.len();
+                self.start_stream();
+            }
+            KeyCode::Char('s') => {
+                self.since_index = (self.since_index + 1) % SINCE_PRESETS.len();
+                self.start_stream();
+            }
+            KeyCode::Char('e') => {
+                self.save_dialog =
+                    Some(SaveDialog::new(&self.container_name, self.show_timestamps));
+            }
+            KeyCode::Char('/') => {
+                self.search_input = Some(TextInput::new());
+                self.update_search("");
+            }
+            KeyCode::Char('n') => {
+                self.jump_to_match(true);
+            }
+            KeyCode::Char('N') => {
+                self.jump_to_match(false);
+            }
+            KeyCode::Char('g') if self.search_regex.is_some() || self.filter_mode => {
+                self.filter_mode = !self.filter_mode;
+                self.scroll_offset = 0;
+                self.follow = true;
+            }
+            _ => {}
+        }
+        true
+    }
+
+    fn format_line(&self, index: usize, line: &LogLine) -> Line<'static> {
+        let theme = current_theme();
+        let mut spans = Vec::new();
+
+        if self.show_timestamps && !line.timestamp.is_empty() {
+            spans.push(Span::styled(
+                format!("{} ", line.timestamp),
+                theme.muted_style(),
+            ));
+        }
+
+        let style = match line.source {
+            LogSource::Stdout => theme.normal_style(),
+            LogSource::Stderr => theme.error_style(),
+        };
+
+        // Split the message around search matches
+        let match_style = if self.current_match == Some(index) {
+            theme.selected_style().add_modifier(Modifier::BOLD)
+        } else {
+            theme.highlight_style().add_modifier(Modifier::REVERSED)
+        };
+        let mut last = 0;
+        if let Some(regex) = &self.search_regex {
+            for m in regex.find_iter(&line.message) {
+                if m.start() == m.end() {
+                    continue;
+                }
+                if m.start() > last {
+                    spans.push(Span::styled(
+                        line.message[last..m.start()].to_string(),
+                        style,
+                    ));
+                }
+                spans.push(Span::styled(m.as_str().to_string(), match_style));
+                last = m.end();
+            }
+        }
+        if last < line.message.len() || spans.is_empty() {
+            spans.push(Span::styled(line.message[last..].to_string(), style));
+        }
+
+        Line::from(spans)
+    }
+
+    pub fn render(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let theme = current_theme();
+
+        // Reserve a line for the search prompt or the status message
+        let (log_area, bottom_area) =
+            if self.search_input.is_some() || self.status_message.is_some() {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(0), Constraint::Length(1)])
+                    .split(area);
+                (chunks[0], Some(chunks[1]))
+            } else {
+                (area, None)
+            };
+
+        let search_flags = match (&self.search_error, self.search_regex.is_some()) {
+            (Some(error), _) => format!(" | /{}: {}", self.search_query, error),
+            (None, true) => format!(
+                " | /{}: {} matches{}",
+                self.search_query,
+                self.matches.len(),
+                if self.filter_mode { " (filter)" } else { "" }
+            ),
+            (None, false) => String::new(),
+        };
+        let flags = format!(
+            "{}tail {} | since {}{}{}{}",
+            if self.follow {
+                "follow | "
+            } else {
+                "paused | "
+            },
+            TAIL_PRESETS[self.tail_index],
+            SINCE_PRESETS[self.since_index].1,
+            if self.wrap { " | wrap" } else { "" },
+            search_flags,
+            self.stream_status
+                .as_ref()
+                .map(|status| format!(" | {}", status))
+                .unwrap_or_default(),
+        );
+        let block = Block::default()
+            .title(format!(
+                "Logs: {} ({} lines) [{}]",
+                self.container_name,
+                self.buffer.len(),
+                flags
+            ))
+            .borders(Borders::ALL)
+            .border_style(theme.modal_border_style());
+
+        let inner = block.inner(log_area);
+        let height = inner.height as usize;
+        let width = inner.width.max(1) as usize;
+
+        // Walk back from the bottom of the view until the area is filled
+        let end = self.displayed_len().saturating_sub(self.scroll_offset);
+        let indices: Box<dyn Iterator<Item = usize>> = if self.filter_mode {
+            Box::new(self.matches.range(..end).rev().copied())
+        } else {
+            Box::new((self.buffer.first_index..self.buffer.first_index + end).rev())
+        };
+
+        let mut visible = Vec::new();
+        let mut used = 0;
+        for index in indices {
+            let Some(line) = self.buffer.get(index) else {
+                continue;
+            };
+            let line = self.format_line(index, line);
+            let rows = if self.wrap {
+                line.width().max(1).div_ceil(width)
+            } else {
+                1
+            };
+            if used + rows > height && !visible.is_empty() {
+                break;
+            }
+            used += rows;
+            visible.push(line);
+        }
+        visible.reverse();
+
+        let mut paragraph = Paragraph::new(visible).block(block);
+        if self.wrap {
+            paragraph = paragraph.wrap(Wrap { trim: false });
+        }
+
+        f.render_widget(paragraph, log_area);
+
+        if let Some(bottom_area) = bottom_area {
+            if let Some(input) = &self.search_input {
+                let prompt = Paragraph::new(input.line(
+                    Span::styled("/", theme.highlight_style()),
+                    theme.normal_style(),
+                    bottom_area.width,
+                    true,
+                ));
+                f.render_widget(prompt, bottom_area);
+            } else if let Some(message) = &self.status_message {
+                let status = Paragraph::new(message.text.clone()).style(message.style());
+                f.render_widget(status, bottom_area);
+            }
+        }
+
+        if let Some(dialog) = &self.save_dialog {
+            self.render_save_dialog(f, area, dialog);
+        }
+    }
+
+    fn render_save_dialog(&self, f: &mut Frame, area: ratatui::layout::Rect, dialog: &SaveDialog) {
+        let theme = current_theme();
+        let popup_area = crate::ui::centered_rect(60, 40, area);
+
+        // Clear the background
+        f.render_widget(Clear, popup_area);
+
+        let field_style = |index: usize| {
+            if dialog.focus == index {
+                theme.selected_style()
+            } else {
+                theme.normal_style()
+            }
+        };
+        let checkbox = |checked: bool| if checked { "[x] " } else { "[ ] " };
+
+        let path_line = dialog.path.line(
+            Span::styled("File: ", theme.highlight_style()),
+            field_style(0),
+            popup_area.width.saturating_sub(2),
+            dialog.focus == 0,
+        );
+
+        let mut lines = vec![
+            path_line,
+            Line::from(""),
+            Line::from(Span::styled(
+                format!(
+                    "{}Full history (fetch all logs again)",
+                    checkbox(dialog.full_history)
+                ),
+                field_style(1),
+            )),
+            Line::from(Span::styled(
+                format!("{}Strip ANSI codes", checkbox(dialog.strip_ansi)),
+                field_style(2),
+            )),
+            Line::from(Span::styled(
+                format!("{}Prefix timestamps", checkbox(dialog.timestamps)),
+                field_style(3),
+            )),
+        ];
+        if let Some(error) = &dialog.error {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(error.clone(), theme.error_style())));
+        }
+
+        let content_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(popup_area);
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .title(format!("Save logs: {}", self.container_name))
+                .borders(Borders::ALL)
+                .border_style(theme.modal_border_style()),
+        );
+        f.render_widget(paragraph, content_area[0]);
+
+        let help = Paragraph::new("[↑/↓/Tab] Field   [Space] Toggle   [Enter] Save   [Esc] Cancel")
+            .style(theme.muted_style())
+            .alignment(Alignment::Center);
+        f.render_widget(help, content_area[1]);
+    }
+
+    pub fn render_help(&self) -> &'static str {
+        if self.save_dialog.is_some() {
+            "[↑/↓/Tab] Field   [Space] Toggle   [Enter] Save   [Esc] Cancel"
+        } else if self.search_input.is_some() {
+            "Type regex   [Enter] Confirm   [Esc] Clear Search"
+        } else {
+            "[↑/↓/PgUp/PgDn] Scroll   [End] Bottom   [/] Search   [N/Shift+N] Next/Prev   [G] Filter   [F] Follow   [T] Timestamps   [W] Wrap   [L] Tail   [S] Since   [E] Save   [Esc] Close"
+        }
+    }
+}
+
+impl Drop for LogViewer {
+    fn drop(&mut self) {
+        self.stream_token.cancel();
+    }
+}
+
+// Creates the file, never overwrites, and returns the number of lines written
+async fn write_logs(
+    client: &DockerClient,
+    container_id: &str,
+    buffered: Option<Vec<LogLine>>,
+    path: &str,
+    timestamps: bool,
+    strip: bool,
+) -> Result<usize, String> {
+    let file = tokio::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(Path::new(path))
+        .await
+        .map_err(|e| format!("Failed to create {}: {}", path, e))?;
+    let mut writer = BufWriter::new(file);
+    let write_error = |e: std::io::Error| format!("Failed to write {}: {}", path, e);
+
+    let mut count = 0;
+    match buffered {
+        Some(lines) => {
+            for line in &lines {
+                write_log_line(&mut writer, line, timestamps, strip)
+                    .await
+                    .map_err(write_error)?;
+                count += 1;
+            }
+        }
+        None => {
+            let mut stream = client.stream_logs(container_id, false, 0, "all");
+            let mut decoder = LogDecoder::default();
+            while let Some(output) = stream.next().await {
+                let output = output.map_err(|e| format!("Failed to fetch logs: {}", e))?;
+                for line in decoder.decode(&output) {
+                    write_log_line(&mut writer, &line, timestamps, strip)
+                        .await
+                        .map_err(write_error)?;
+                    count += 1;
+                }
+            }
+            for line in decoder.finish() {
+                write_log_line(&mut writer, &line, timestamps, strip)
+                    .await
+                    .map_err(write_error)?;
+                count += 1;
+            }
+        }
+    }
+
+    writer.flush().await.map_err(write_error)?;
+    Ok(count)
+}
+
+async fn write_log_line(
+    writer: &mut BufWriter<tokio::fs::File>,
+    line: &LogLine,
+    timestamps: bool,
+    strip: bool,
+) -> std::io::Result<()> {
+    if timestamps && !line.timestamp.is_empty() {
+        writer.write_all(line.timestamp.as_bytes()).await?;
+        writer.write_all(b" ").await?;
+    }
+    if strip {
+        writer
+            .write_all(strip_ansi(&line.message).as_bytes())
+            .await?;
+    } else {
+        writer.write_all(line.message.as_bytes()).await?;
+    }
+    writer.write_all(b"\n").await
+}
@@ -1,41 +1,126 @@
-use crate::components::Component;
-use crate::docker::DockerClient;
+use crate::app::AppEvent;
+use crate::components::{Component, StatusMessage};
+use crate::docker::{DockerClient, NetworkConfig, NetworkInfo, NetworkInspectDetails};
 use crate::theme::current_theme;
+use crate::ui_form::{Form, FormOutcome};
+use crate::ui_json::JsonView;
 use async_trait::async_trait;
 use color_eyre::Result;
 use crossterm::event::KeyCode;
 use ratatui::{
     Frame,
-    style::{Color, Style},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, Wrap},
 };
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::net::IpAddr;
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, mpsc};
+
+// Lines moved by [PgUp]/[PgDn] in the inspect modal
+const PAGE_SCROLL: usize = 10;
+
+// Column the table is sorted by, cycled with [S]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NetworkSort {
+    Name,
+    Driver,
+    Scope,
+    Subnet,
+    Containers,
+    Created,
+}
+
+impl NetworkSort {
+    fn next(self) -> Self {
+        match self {
+            NetworkSort::Name => NetworkSort::Driver,
+            NetworkSort::Driver => NetworkSort::Scope,
+            NetworkSort::Scope => NetworkSort::Subnet,
+            NetworkSort::Subnet => NetworkSort::Containers,
+            NetworkSort::Containers => NetworkSort::Created,
+            NetworkSort::Created => NetworkSort::Name,
+        }
+    }
+
+    fn compare(self, a: &NetworkInfo, b: &NetworkInfo) -> Ordering {
+        let ordering = match self {
+            NetworkSort::Name => Ordering::Equal,
+            NetworkSort::Driver => a.driver.cmp(&b.driver),
+            NetworkSort::Scope => a.scope.cmp(&b.scope),
+            NetworkSort::Subnet => a.subnet.cmp(&b.subnet),
+            NetworkSort::Containers => a.containers.cmp(&b.containers),
+            NetworkSort::Created => a.created.cmp(&b.created),
+        };
+        // Ties keep a stable order by name
+        ordering.then_with(|| a.name.cmp(&b.name))
+    }
+}
 
 pub struct NetworksUI {
     tab_num: usize,
     docker_client: Arc<Mutex<DockerClient>>,
     selected_index: usize,
-    networks: Vec<String>,
+    networks: Vec<NetworkInfo>,
+    sort: NetworkSort,
+    sort_descending: bool,
     last_tick: std::time::Instant,
+    // Inspect modal, the connected containers can be selected
+    inspect_data: Option<NetworkInspectDetails>,
+    inspect_scroll: usize,
+    selected_endpoint: usize,
+    // Scrolling with [PgUp]/[PgDn] stops following the selected container
+    follow_endpoint: bool,
+    // Raw JSON of the inspected network, replaces the fields when open
+    json_view: Option<JsonView>,
+    // App-level requests (jump to a container)
+    event_tx: mpsc::UnboundedSender<AppEvent>,
+    create_form: Option<Form>,
+    status_message: Option<StatusMessage>,
 }
 
 impl NetworksUI {
-    pub fn new(docker_client: Arc<Mutex<DockerClient>>, tab_num: usize) -> Self {
+    pub fn new(
+        docker_client: Arc<Mutex<DockerClient>>,
+        tab_num: usize,
+        event_tx: mpsc::UnboundedSender<AppEvent>,
+    ) -> Self {
         Self {
             tab_num,
             docker_client,
             selected_index: 0,
             networks: Vec::new(),
+            sort: NetworkSort::Name,
+            sort_descending: false,
             last_tick: std::time::Instant::now(),
+            inspect_data: None,
+            inspect_scroll: 0,
+            selected_endpoint: 0,
+            follow_endpoint: true,
+            json_view: None,
+            event_tx,
+            create_form: None,
+            status_message: None,
         }
     }
 
     async fn refresh_now(&mut self) -> Result<()> {
-        let client = self.docker_client.lock().await;
-        match client.list_networks().await {
+        let result = self.docker_client.lock().await.list_networks().await;
+        match result {
             Ok(networks) => {
+                let selected_id = self
+                    .get_selected_network()
+                    .map(|network| network.id.clone());
                 self.networks = networks;
+                self.sort_networks();
+                // Keep the same network selected when the order changes
+                if let Some(index) = selected_id
+                    .and_then(|id| self.networks.iter().position(|network| network.id == id))
+                {
+                    self.selected_index = index;
+                }
                 // Adjust selected index if necessary
                 if self.selected_index >= self.networks.len() && !self.networks.is_empty() {
                     self.selected_index = self.networks.len() - 1;
@@ -49,10 +134,43 @@ impl NetworksUI {
         }
     }
 
-    fn get_selected_network(&self) -> Option<&String> {
+    fn get_selected_network(&self) -> Option<&NetworkInfo> {
         self.networks.get(self.selected_index)
     }
 
+    fn sort_networks(&mut self) {
+        let (sort, descending) = (self.sort, self.sort_descending);
+        self.networks.sort_by(|a, b| {
+            let ordering = sort.compare(a, b);
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+
+    fn change_sort(&mut self, sort: NetworkSort, descending: bool) {
+        let selected_id = self
+            .get_selected_network()
+            .map(|network| network.id.clone());
+        self.sort = sort;
+        self.sort_descending = descending;
+        self.sort_networks();
+        self.selected_index = selected_id
+            .and_then(|id| self.networks.iter().position(|network| network.id == id))
+            .unwrap_or(0);
+    }
+
+    // "Driver ▲" on the sorted column
+    fn header(&self, title: &'static str, sort: NetworkSort) -> String {
+        match (self.sort == sort, self.sort_descending) {
+            (true, false) => format!("{} ▲", title),
+            (true, true) => format!("{} ▼", title),
+            (false, _) => title.to_string(),
+        }
+    }
+
     async fn delete_network(&self, network_name: &str) -> Result<()> {
         eprintln!("Deleting network: {}", network_name);
         // TODO: Implement network deletion
@@ -60,19 +178,407 @@ impl NetworksUI {
         Ok(())
     }
 
-    async fn create_network(&self) -> Result<()> {
-        eprintln!("Creating new network...");
-        // TODO: Implement network creation
-        // Should probably show a dialog to input network name and options
-        Ok(())
+    fn open_create_form(&mut self) {
+        self.create_form = Some(
+            Form::new("Create Network", "Create")
+                .text(
+                    "name",
+                    "Name",
+                    "Letters, digits, '_', '.' and '-'",
+                    validate_name,
+                )
+                .choice(
+                    "driver",
+                    "Driver",
+                    "overlay needs swarm mode, macvlan a parent=<interface> option",
+                    &["bridge", "overlay", "macvlan"],
+                )
+                .text(
+                    "subnet",
+                    "Subnet",
+                    "CIDR such as 172.20.0.0/16, empty to let the daemon pick one",
+                    validate_cidr,
+                )
+                .text(
+                    "gateway",
+                    "Gateway",
+                    "Address inside the subnet, empty for the first one",
+                    validate_address,
+                )
+                .text(
+                    "ip_range",
+                    "IP Range",
+                    "CIDR inside the subnet containers get addresses from",
+                    validate_cidr,
+                )
+                .toggle("internal", "Internal", "No access to the outside", false)
+                .toggle(
+                    "attachable",
+                    "Attachable",
+                    "Standalone containers can join a swarm network",
+                    false,
+                )
+                .toggle("ipv6", "IPv6", "Needed for an IPv6 subnet", false)
+                .text(
+                    "labels",
+                    "Labels",
+                    "key=value, separated by commas",
+                    validate_key_values,
+                )
+                .text(
+                    "options",
+                    "Driver Options",
+                    "key=value, separated by commas, e.g. parent=eth0",
+                    validate_key_values,
+                ),
+        );
+    }
+
+    // Checks across fields, the field validators already passed
+    fn network_config(&self, form: &Form) -> Result<NetworkConfig, (&'static str, String)> {
+        let name = form.value("name");
+        if self.networks.iter().any(|network| network.name == name) {
+            return Err(("name", format!("Network {} already exists", name)));
+        }
+
+        let subnet = form.value("subnet");
+        let (gateway, ip_range) = (form.value("gateway"), form.value("ip_range"));
+        if let Ok((network, prefix)) = parse_cidr(subnet) {
+            if !gateway.is_empty()
+                && let Ok(address) = gateway.parse::<IpAddr>()
+                && !cidr_contains(network, prefix, address)
+            {
+                return Err(("gateway", format!("{} is outside {}", gateway, subnet)));
+            }
+            if let Ok((range, range_prefix)) = parse_cidr(ip_range)
+                && (range_prefix < prefix || !cidr_contains(network, prefix, range))
+            {
+                return Err(("ip_range", format!("{} is outside {}", ip_range, subnet)));
+            }
+            if network.is_ipv6() && !form.is_checked("ipv6") {
+                return Err(("ipv6", "Enable IPv6 for an IPv6 subnet".to_string()));
+            }
+        } else if !gateway.is_empty() {
+            return Err(("gateway", "A gateway needs a subnet".to_string()));
+        } else if !ip_range.is_empty() {
+            return Err(("ip_range", "An IP range needs a subnet".to_string()));
+        }
+
+        Ok(NetworkConfig {
+            name: name.to_string(),
+            driver: form.value("driver").to_string(),
+            subnet: subnet.to_string(),
+            gateway: gateway.to_string(),
+            ip_range: ip_range.to_string(),
+            internal: form.is_checked("internal"),
+            attachable: form.is_checked("attachable"),
+            enable_ipv6: form.is_checked("ipv6"),
+            labels: parse_key_values(form.value("labels")),
+            options: parse_key_values(form.value("options")),
+        })
+    }
+
+    async fn handle_create_input(&mut self, key: KeyCode) {
+        let Some(form) = &mut self.create_form else {
+            return;
+        };
+        match form.handle_input(key) {
+            FormOutcome::Pending => {}
+            FormOutcome::Cancelled => self.create_form = None,
+            FormOutcome::Submitted => {
+                let Some(mut form) = self.create_form.take() else {
+                    return;
+                };
+                match self.network_config(&form) {
+                    Ok(config) => self.create_network(config).await,
+                    Err((key, error)) => {
+                        form.set_error(key, error);
+                        self.create_form = Some(form);
+                    }
+                }
+            }
+        }
+    }
+
+    async fn create_network(&mut self, config: NetworkConfig) {
+        let result = self
+            .docker_client
+            .lock()
+            .await
+            .create_network(&config)
+            .await;
+        match result {
+            Ok(_) => {
+                self.status_message = Some(StatusMessage::info(format!(
+                    "Created network {}",
+                    config.name
+                )));
+                let _ = self.refresh_now().await;
+                if let Some(index) = self
+                    .networks
+                    .iter()
+                    .position(|network| network.name == config.name)
+                {
+                    self.selected_index = index;
+                }
+            }
+            Err(e) => {
+                self.status_message = Some(StatusMessage::error(format!(
+                    "Failed to create network {}: {}",
+                    config.name,
+                    DockerClient::format_error(&e)
+                )));
+            }
+        }
     }
 
-    async fn inspect_network(&self, network_name: &str) -> Result<()> {
-        eprintln!("Inspecting network: {}", network_name);
-        // TODO: Implement network inspection
-        // Show detailed info including connected containers
+    async fn inspect_network(&mut self, network_name: &str) -> Result<()> {
+        let client = self.docker_client.lock().await;
+        match client.inspect_network(network_name).await {
+            Ok(details) => {
+                self.inspect_data = Some(details);
+                self.inspect_scroll = 0;
+                self.selected_endpoint = 0;
+                self.follow_endpoint = true;
+            }
+            Err(e) => {
+                self.status_message = Some(StatusMessage::error(format!(
+                    "Failed to inspect network {}: {}",
+                    network_name,
+                    DockerClient::format_error(&e)
+                )));
+            }
+        }
         Ok(())
     }
+
+    async fn toggle_json_view(&mut self) {
+        if self.json_view.take().is_some() {
+            return;
+        }
+        let Some(inspect_data) = &self.inspect_data else {
+            return;
+        };
+
+        let client = self.docker_client.lock().await;
+        match client.inspect_network_json(&inspect_data.id).await {
+            Ok(value) => {
+                self.json_view = Some(JsonView::new(
+                    format!("Network Inspection: {} (raw JSON)", inspect_data.name),
+                    value,
+                ));
+            }
+            Err(e) => {
+                self.status_message = Some(StatusMessage::error(format!(
+                    "Failed to inspect network {}: {}",
+                    inspect_data.name,
+                    DockerClient::format_error(&e)
+                )));
+            }
+        }
+    }
+
+    fn handle_inspect_input(&mut self, key: KeyCode) {
+        let Some(data) = &self.inspect_data else {
+            return;
+        };
+        let endpoints = data.containers.len();
+        match key {
+            KeyCode::Esc => {
+                self.inspect_data = None;
+                self.json_view = None;
+            }
+            KeyCode::Up => {
+                self.selected_endpoint = self.selected_endpoint.saturating_sub(1);
+                self.follow_endpoint = true;
+            }
+            KeyCode::Down => {
+                self.selected_endpoint =
+                    (self.selected_endpoint + 1).min(endpoints.saturating_sub(1));
+                self.follow_endpoint = true;
+            }
+            KeyCode::PageUp => {
+                self.inspect_scroll = self.inspect_scroll.saturating_sub(PAGE_SCROLL);
+                self.follow_endpoint = false;
+            }
+            KeyCode::PageDown => {
+                self.inspect_scroll += PAGE_SCROLL;
+                self.follow_endpoint = false;
+            }
+            KeyCode::Enter => {
+                if let Some(container) = data.containers.get(self.selected_endpoint) {
+                    let _ = self.event_tx.send(AppEvent::ShowContainer {
+                        container_id: container.id.clone(),
+                    });
+                    self.inspect_data = None;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Lines of the modal, with the line of each connected container
+    fn inspect_lines<'a>(&self, data: &'a NetworkInspectDetails) -> (Vec<Line<'a>>, Vec<usize>) {
+        let theme = current_theme();
+        let field = |label: &str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{}: ", label), theme.highlight_style()),
+                Span::styled(value, theme.normal_style()),
+            ])
+        };
+        let yes_no = |value: bool| if value { "yes" } else { "no" }.to_string();
+        let sorted = |map: &'a HashMap<String, String>| {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort();
+            entries
+        };
+
+        let mut lines = vec![
+            Line::from(Span::styled("Basic Information", theme.header_style())),
+            Line::from(""),
+            field("ID", data.id.clone()),
+            field("Name", data.name.clone()),
+            field("Driver", data.driver.clone()),
+            field("Scope", data.scope.clone()),
+            field("Created", data.created_formatted.clone()),
+            field("Internal", yes_no(data.internal)),
+            field("Attachable", yes_no(data.attachable)),
+            field("Ingress", yes_no(data.ingress)),
+            field("IPv6", yes_no(data.enable_ipv6)),
+            Line::from(""),
+            Line::from(Span::styled("IPAM", theme.header_style())),
+            Line::from(""),
+            field("Driver", data.ipam_driver.clone()),
+        ];
+        for config in &data.ipam_config {
+            let mut spans = vec![
+                Span::raw("  "),
+                Span::styled(config.subnet.clone(), theme.info_style()),
+            ];
+            if !config.gateway.is_empty() {
+                spans.push(Span::styled(
+                    format!("  gateway {}", config.gateway),
+                    theme.normal_style(),
+                ));
+            }
+            if !config.ip_range.is_empty() {
+                spans.push(Span::styled(
+                    format!("  range {}", config.ip_range),
+                    theme.normal_style(),
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+
+        for (title, map) in [("Options", &data.options), ("Labels", &data.labels)] {
+            if map.is_empty() {
+                continue;
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(title, theme.header_style())));
+            lines.push(Line::from(""));
+            for (key, value) in sorted(map) {
+                lines.push(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled(format!("{}=", key), theme.highlight_style()),
+                    Span::styled(value.as_str(), theme.info_style()),
+                ]));
+            }
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("Connected Containers ({})", data.containers.len()),
+            theme.header_style(),
+        )));
+        lines.push(Line::from(""));
+        if data.containers.is_empty() {
+            lines.push(Line::from(Span::styled("  None", theme.muted_style())));
+        }
+
+        let mut endpoint_lines = Vec::new();
+        for (i, container) in data.containers.iter().enumerate() {
+            endpoint_lines.push(lines.len());
+            let style = if i == self.selected_endpoint {
+                theme.selected_style()
+            } else {
+                theme.normal_style()
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {}", container.name), style),
+                Span::styled(
+                    format!("  {}", DockerClient::format_container_id(&container.id)),
+                    theme.muted_style(),
+                ),
+            ]));
+
+            let addresses: Vec<String> = [
+                ("IPv4", &container.ipv4_address),
+                ("IPv6", &container.ipv6_address),
+                ("MAC", &container.mac_address),
+            ]
+            .into_iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(label, value)| format!("{} {}", label, value))
+            .collect();
+            lines.push(Line::from(Span::styled(
+                format!("    {}", addresses.join("   ")),
+                theme.info_style(),
+            )));
+        }
+
+        (lines, endpoint_lines)
+    }
+
+    fn render_inspect_modal(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let Some(data) = &self.inspect_data else {
+            return;
+        };
+        let theme = current_theme();
+        let popup_area = crate::ui::centered_rect(80, 80, area);
+
+        // Clear the background
+        f.render_widget(Clear, popup_area);
+
+        let content_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(popup_area);
+
+        let help = Paragraph::new(self.render_help())
+            .style(theme.muted_style())
+            .alignment(Alignment::Center);
+        f.render_widget(help, content_area[1]);
+
+        if let Some(json_view) = &self.json_view {
+            json_view.render(f, content_area[0]);
+            return;
+        }
+
+        let (lines, endpoint_lines) = self.inspect_lines(data);
+        let height = content_area[0].height.saturating_sub(2) as usize;
+        let max_scroll = lines.len().saturating_sub(height);
+        // Keep the selected container and its addresses on screen
+        let scroll = match endpoint_lines.get(self.selected_endpoint) {
+            Some(&line) if self.follow_endpoint => {
+                let scroll = self.inspect_scroll.min(line);
+                scroll.max((line + 2).saturating_sub(height))
+            }
+            _ => self.inspect_scroll,
+        }
+        .min(max_scroll);
+
+        let paragraph = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(format!("Network Inspection: {}", data.name))
+                    .borders(Borders::ALL)
+                    .border_style(theme.modal_border_style()),
+            )
+            .wrap(Wrap { trim: false })
+            .scroll((scroll as u16, 0));
+        f.render_widget(paragraph, content_area[0]);
+    }
 }
 
 #[async_trait]
@@ -95,9 +601,35 @@ impl Component for NetworksUI {
             self.last_tick = now;
             let _ = self.refresh_now().await;
         }
+
+        if self
+            .status_message
+            .as_ref()
+            .is_some_and(|message| message.is_expired())
+        {
+            self.status_message = None;
+        }
     }
 
     async fn handle_input(&mut self, key: KeyCode) -> Result<bool> {
+        // Handle modal input first
+        if self.create_form.is_some() {
+            self.handle_create_input(key).await;
+            return Ok(true);
+        }
+        if self.inspect_data.is_some() {
+            if key == KeyCode::Char('j') {
+                self.toggle_json_view().await;
+            } else if let Some(json_view) = &mut self.json_view
+                && key != KeyCode::Esc
+            {
+                json_view.handle_input(key);
+            } else {
+                self.handle_inspect_input(key);
+            }
+            return Ok(true); // Modal is open, consume all events
+        }
+
         match key {
             KeyCode::Up => {
                 if self.selected_index > 0 {
@@ -116,19 +648,28 @@ impl Component for NetworksUI {
                 self.refresh_now().await?;
                 Ok(true)
             }
+            KeyCode::Char('s') => {
+                self.change_sort(self.sort.next(), false);
+                Ok(true)
+            }
+            KeyCode::Char('S') => {
+                self.change_sort(self.sort, !self.sort_descending);
+                Ok(true)
+            }
             KeyCode::Char('d') => {
-                if let Some(network_name) = self.get_selected_network() {
-                    self.delete_network(network_name).await?;
+                if let Some(network) = self.get_selected_network() {
+                    self.delete_network(&network.name).await?;
                 }
                 Ok(true)
             }
             KeyCode::Char('c') => {
-                self.create_network().await?;
+                self.open_create_form();
                 Ok(true)
             }
             KeyCode::Char('i') => {
-                if let Some(network_name) = self.get_selected_network() {
-                    self.inspect_network(network_name).await?;
+                if let Some(network) = self.get_selected_network() {
+                    let network_name = network.name.clone();
+                    self.inspect_network(&network_name).await?;
                 }
                 Ok(true)
             }
@@ -139,6 +680,20 @@ impl Component for NetworksUI {
     fn render(&self, f: &mut Frame, area: ratatui::layout::Rect) {
         let theme = current_theme();
 
+        // Reserve a line for the status message if there is one
+        let table_area = if let Some(message) = &self.status_message {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(1)])
+                .split(area);
+
+            let status = Paragraph::new(message.text.clone()).style(message.style());
+            f.render_widget(status, chunks[1]);
+            chunks[0]
+        } else {
+            area
+        };
+
         if self.networks.is_empty() {
             let paragraph = Paragraph::new("No networks found or loading...")
                 .block(
@@ -148,36 +703,280 @@ impl Component for NetworksUI {
                         .border_style(theme.border_style()),
                 )
                 .style(theme.muted_style());
-            f.render_widget(paragraph, area);
+            f.render_widget(paragraph, table_area);
         } else {
-            let items: Vec<ListItem> = self
+            let headers = Row::new(vec![
+                Cell::from(self.header("Name", NetworkSort::Name)).style(theme.header_style()),
+                Cell::from("Network ID").style(theme.header_style()),
+                Cell::from(self.header("Driver", NetworkSort::Driver)).style(theme.header_style()),
+                Cell::from(self.header("Scope", NetworkSort::Scope)).style(theme.header_style()),
+                Cell::from(self.header("Subnet", NetworkSort::Subnet)).style(theme.header_style()),
+                Cell::from("Gateway").style(theme.header_style()),
+                Cell::from("Flags").style(theme.header_style()),
+                Cell::from(self.header("Containers", NetworkSort::Containers))
+                    .style(theme.header_style()),
+                Cell::from(self.header("Created", NetworkSort::Created))
+                    .style(theme.header_style()),
+            ]);
+
+            let rows: Vec<Row> = self
                 .networks
                 .iter()
                 .enumerate()
                 .map(|(i, network)| {
+                    let flags: Vec<&str> = [
+                        (network.internal, "internal"),
+                        (network.attachable, "attachable"),
+                    ]
+                    .into_iter()
+                    .filter_map(|(set, flag)| set.then_some(flag))
+                    .collect();
+                    // Networks nobody uses are candidates for cleanup
                     let style = if i == self.selected_index {
                         theme.selected_style()
+                    } else if network.containers == 0 {
+                        theme.muted_style()
                     } else {
                         theme.normal_style()
                     };
-                    ListItem::new(network.clone()).style(style)
+                    Row::new(vec![
+                        Cell::from(network.name.clone()),
+                        Cell::from(network.display_id.clone()),
+                        Cell::from(network.driver.clone()),
+                        Cell::from(network.scope.clone()),
+                        Cell::from(network.subnet.clone()),
+                        Cell::from(network.gateway.clone()),
+                        Cell::from(flags.join(", ")),
+                        Cell::from(network.containers.to_string()),
+                        Cell::from(network.created_ago.clone()),
+                    ])
+                    .style(style)
                 })
                 .collect();
 
-            let list = List::new(items)
-                .block(
-                    Block::default()
-                        .title(format!("Networks ({})", self.networks.len()))
-                        .borders(Borders::ALL)
-                        .border_style(theme.border_style()),
-                )
-                .style(theme.normal_style());
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Percentage(18), // Name
+                    Constraint::Length(12),     // Network ID
+                    Constraint::Length(10),     // Driver
+                    Constraint::Length(8),      // Scope
+                    Constraint::Percentage(18), // Subnet
+                    Constraint::Percentage(14), // Gateway
+                    Constraint::Length(19),     // Flags
+                    Constraint::Length(12),     // Containers
+                    Constraint::Length(10),     // Created
+                ],
+            )
+            .header(headers)
+            .block(
+                Block::default()
+                    .title(format!("Networks ({})", self.networks.len()))
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .column_spacing(1);
+
+            f.render_widget(table, table_area);
+        }
 
-            f.render_widget(list, area);
+        // Render modal if active
+        self.render_inspect_modal(f, area);
+        if let Some(form) = &self.create_form {
+            form.render(f, area);
         }
     }
 
     fn render_help(&self) -> &'static str {
-        "[↑/↓] Select   [C] Create   [D] Delete   [I] Inspect   [R/F5] Refresh   [Q] Quit"
+        if let Some(form) = &self.create_form {
+            return form.render_help();
+        }
+        if self.json_view.is_some() {
+            return "[↑/↓/PgUp/PgDn] Move   [Y] Copy Field   [Shift+Y] Copy All   [J] Fields   [Esc] Close";
+        }
+        if self.inspect_data.is_some() {
+            return "[↑/↓] Select Container   [Enter] Go To Container   [PgUp/PgDn] Scroll   [J] Raw JSON   [Esc] Close";
+        }
+        "[↑/↓] Select   [S] Sort   [Shift+S] Reverse   [C] Create   [D] Delete   [I] Inspect   [R/F5] Refresh   [Q] Quit"
+    }
+}
+
+fn validate_name(name: &str) -> Result<(), String> {
+    let mut chars = name.chars();
+    match chars.next() {
+        None => Err("Name is required".to_string()),
+        Some(first) if !first.is_ascii_alphanumeric() => {
+            Err("Name must start with a letter or digit".to_string())
+        }
+        _ if !chars.all(|c| c.is_ascii_alphanumeric() || "_.-".contains(c)) => {
+            Err("Name may only contain letters, digits, '_', '.' and '-'".to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
+// Empty means "let the daemon decide"
+fn validate_cidr(cidr: &str) -> Result<(), String> {
+    if cidr.is_empty() {
+        return Ok(());
+    }
+    parse_cidr(cidr).map(|_| ())
+}
+
+fn validate_address(address: &str) -> Result<(), String> {
+    if address.is_empty() || address.parse::<IpAddr>().is_ok() {
+        Ok(())
+    } else {
+        Err(format!("{} is not an IP address", address))
+    }
+}
+
+fn validate_key_values(pairs: &str) -> Result<(), String> {
+    for pair in pairs
+        .split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+    {
+        match pair.split_once('=') {
+            Some((key, _)) if !key.trim().is_empty() => {}
+            _ => return Err(format!("{} is not key=value", pair)),
+        }
+    }
+    Ok(())
+}
+
+fn parse_key_values(pairs: &str) -> HashMap<String, String> {
+    pairs
+        .split(',')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
+// "172.20.0.0/16" -> (172.20.0.0, 16), host bits must be zero as the daemon requires
+fn parse_cidr(cidr: &str) -> Result<(IpAddr, u32), String> {
+    let (address, prefix) = cidr
+        .split_once('/')
+        .ok_or_else(|| format!("{} is missing the /prefix", cidr))?;
+    let address: IpAddr = address
+        .parse()
+        .map_err(|_| format!("{} is not an IP address", address))?;
+    let bits = if address.is_ipv4() { 32 } else { 128 };
+    let prefix: u32 = prefix
+        .parse()
+        .ok()
+        .filter(|prefix| *prefix <= bits)
+        .ok_or_else(|| format!("Prefix must be between 0 and {}", bits))?;
+
+    let network = mask(address, prefix);
+    if network != address {
+        return Err(format!("Host bits are set, use {}/{}", network, prefix));
+    }
+    Ok((address, prefix))
+}
+
+// Address with only the first `prefix` bits kept
+fn mask(address: IpAddr, prefix: u32) -> IpAddr {
+    match address {
+        IpAddr::V4(v4) => {
+            let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+            IpAddr::V4((u32::from(v4) & mask).into())
+        }
+        IpAddr::V6(v6) => {
+            let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+            IpAddr::V6((u128::from(v6) & mask).into())
+        }
+    }
+}
+
+fn cidr_contains(network: IpAddr, prefix: u32, address: IpAddr) -> bool {
+    network.is_ipv4() == address.is_ipv4() && mask(address, prefix) == network
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(address: &str) -> IpAddr {
+        address.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_cidr_accepts_network_addresses() {
+        assert_eq!(parse_cidr("172.20.0.0/16"), Ok((ip("172.20.0.0"), 16)));
+        assert_eq!(parse_cidr("0.0.0.0/0"), Ok((ip("0.0.0.0"), 0)));
+        assert_eq!(parse_cidr("10.1.2.3/32"), Ok((ip("10.1.2.3"), 32)));
+        assert_eq!(parse_cidr("fd00:1::/64"), Ok((ip("fd00:1::"), 64)));
+    }
+
+    #[test]
+    fn parse_cidr_rejects_bad_prefixes() {
+        assert!(parse_cidr("172.20.0.0").is_err());
+        assert!(parse_cidr("172.20.0.0/").is_err());
+        assert!(parse_cidr("172.20.0.0/33").is_err());
+        assert!(parse_cidr("172.20.0.0/-1").is_err());
+        assert!(parse_cidr("172.20.0.0/x").is_err());
+        assert!(parse_cidr("fd00::/129").is_err());
+        assert!(parse_cidr("172.20.0/16").is_err());
+    }
+
+    #[test]
+    fn parse_cidr_rejects_host_bits() {
+        assert_eq!(
+            parse_cidr("172.20.1.0/16"),
+            Err("Host bits are set, use 172.20.0.0/16".to_string())
+        );
+        assert_eq!(
+            parse_cidr("fd00::1/64"),
+            Err("Host bits are set, use fd00::/64".to_string())
+        );
+    }
+
+    #[test]
+    fn cidr_contains_checks_the_subnet() {
+        let (network, prefix) = parse_cidr("172.20.0.0/16").unwrap();
+        assert!(cidr_contains(network, prefix, ip("172.20.0.1")));
+        assert!(cidr_contains(network, prefix, ip("172.20.255.255")));
+        // Gateway outside the subnet
+        assert!(!cidr_contains(network, prefix, ip("172.21.0.1")));
+        assert!(!cidr_contains(network, prefix, ip("::1")));
+
+        let (network, prefix) = parse_cidr("fd00:1::/64").unwrap();
+        assert!(cidr_contains(network, prefix, ip("fd00:1::1")));
+        assert!(!cidr_contains(network, prefix, ip("fd00:2::1")));
+        assert!(!cidr_contains(network, prefix, ip("10.0.0.1")));
+    }
+
+    #[test]
+    fn validators_accept_empty_optional_fields() {
+        assert!(validate_cidr("").is_ok());
+        assert!(validate_cidr("fd00::/8").is_ok());
+        assert!(validate_cidr("fd00::1/8").is_err());
+        assert!(validate_address("").is_ok());
+        assert!(validate_address("fd00::1").is_ok());
+        assert!(validate_address("172.20.0.256").is_err());
+    }
+
+    #[test]
+    fn validate_name_follows_docker_rules() {
+        assert!(validate_name("backend_net-1.2").is_ok());
+        assert!(validate_name("").is_err());
+        assert!(validate_name("-net").is_err());
+        assert!(validate_name("my net").is_err());
+    }
+
+    #[test]
+    fn key_values_need_a_key() {
+        assert!(validate_key_values("").is_ok());
+        assert!(validate_key_values("a=1, b=").is_ok());
+        assert!(validate_key_values("a=1,b").is_err());
+        assert!(validate_key_values("=1").is_err());
+        assert_eq!(
+            parse_key_values(" a = 1 ,b=2"),
+            HashMap::from([
+                ("a".to_string(), "1".to_string()),
+                ("b".to_string(), "2".to_string())
+            ])
+        );
     }
 }
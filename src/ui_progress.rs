@@ -0,0 +1,333 @@
+use crate::components::StatusMessage;
+use crate::docker::{DockerClient, ProgressUpdate};
+use crate::theme::current_theme;
+
+use crossterm::event::KeyCode;
+use futures::{Stream, StreamExt};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Gauge, Paragraph},
+};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LayerPhase {
+    Waiting,
+    Transferring,
+    Extracting,
+    Complete,
+}
+
+struct LayerState {
+    id: String,
+    phase: LayerPhase,
+    status: String,
+    current: u64,
+    total: u64,
+    // Bytes moved over the network, kept once extraction starts
+    transferred: u64,
+    transfer_total: u64,
+}
+
+impl LayerState {
+    fn ratio(&self) -> f64 {
+        match self.phase {
+            LayerPhase::Waiting => 0.0,
+            LayerPhase::Complete => 1.0,
+            _ if self.total > 0 => (self.current as f64 / self.total as f64).min(1.0),
+            _ => 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TransferStatus {
+    Running,
+    Finished,
+    Failed(String),
+    Cancelled,
+}
+
+enum TransferEvent {
+    Update(ProgressUpdate),
+    Done(Result<(), String>),
+}
+
+/// Per-layer progress of a pull, push, save or load running in background
+pub struct ProgressView {
+    title: String,
+    layers: Vec<LayerState>,
+    last_message: String,
+    status: TransferStatus,
+    scroll: usize,
+    token: CancellationToken,
+    event_rx: mpsc::UnboundedReceiver<TransferEvent>,
+}
+
+impl ProgressView {
+    pub fn start<S>(
+        title: impl Into<String>,
+        stream: S,
+        cancellation_token: &CancellationToken,
+    ) -> Self
+    where
+        S: Stream<Item = Result<ProgressUpdate, bollard::errors::Error>> + Send + 'static,
+    {
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let token = cancellation_token.child_token();
+        let task_token = token.clone();
+
+        tokio::spawn(async move {
+            let mut stream = Box::pin(stream);
+            // Dropping the stream closes the connection, which aborts the transfer
+            let result = loop {
+                tokio::select! {
+                    _ = task_token.cancelled() => return,
+                    update = stream.next() => match update {
+                        Some(Ok(update)) => {
+                            let _ = event_tx.send(TransferEvent::Update(update));
+                        }
+                        Some(Err(e)) => break Err(DockerClient::format_error(&e)),
+                        None => break Ok(()),
+                    },
+                }
+            };
+            let _ = event_tx.send(TransferEvent::Done(result));
+        });
+
+        Self {
+            title: title.into(),
+            layers: Vec::new(),
+            last_message: "Starting...".to_string(),
+            status: TransferStatus::Running,
+            scroll: 0,
+            token,
+            event_rx,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.status == TransferStatus::Running
+    }
+
+    pub fn cancel(&mut self) {
+        if self.is_running() {
+            self.token.cancel();
+            self.status = TransferStatus::Cancelled;
+        }
+    }
+
+    /// Apply received updates, returns true once the transfer has just ended
+    pub fn poll(&mut self) -> bool {
+        let mut ended = false;
+        while let Ok(event) = self.event_rx.try_recv() {
+            match event {
+                TransferEvent::Update(update) => self.apply(update),
+                TransferEvent::Done(result) => {
+                    self.status = match result {
+                        Ok(()) => TransferStatus::Finished,
+                        Err(e) => TransferStatus::Failed(e),
+                    };
+                    ended = true;
+                }
+            }
+        }
+        ended
+    }
+
+    fn apply(&mut self, update: ProgressUpdate) {
+        // "Pulling from library/nginx" carries the tag as ID, it's not a layer
+        if update.id.is_empty() || update.status.starts_with("Pulling from") {
+            if !update.status.is_empty() {
+                self.last_message = if update.id.is_empty() {
+                    update.status
+                } else {
+                    format!("{}: {}", update.id, update.status)
+                };
+            }
+            return;
+        }
+
+        let index = match self.layers.iter().position(|layer| layer.id == update.id) {
+            Some(index) => index,
+            None => {
+                self.layers.push(LayerState {
+                    id: update.id.clone(),
+                    phase: LayerPhase::Waiting,
+                    status: String::new(),
+                    current: 0,
+                    total: 0,
+                    transferred: 0,
+                    transfer_total: 0,
+                });
+                self.layers.len() - 1
+            }
+        };
+        let layer = &mut self.layers[index];
+
+        let status = update.status.as_str();
+        layer.phase = match status {
+            s if ["Downloading", "Pushing", "Saving", "Loading"]
+                .iter()
+                .any(|prefix| s.starts_with(prefix)) =>
+            {
+                if update.total > 0 {
+                    layer.transferred = update.current;
+                    layer.transfer_total = update.total;
+                }
+                LayerPhase::Transferring
+            }
+            "Download complete" | "Verifying Checksum" => {
+                layer.transferred = layer.transfer_total;
+                layer.current = layer.total;
+                LayerPhase::Transferring
+            }
+            s if s.starts_with("Extracting") => {
+                layer.transferred = layer.transfer_total;
+                LayerPhase::Extracting
+            }
+            "Pull complete"
+            | "Already exists"
+            | "Pushed"
+            | "Layer already exists"
+            | "Saved"
+            | "Loaded" => {
+                layer.transferred = layer.transfer_total;
+                LayerPhase::Complete
+            }
+            s if s.starts_with("Mounted from") => LayerPhase::Complete,
+            _ => layer.phase,
+        };
+        if update.total > 0 {
+            layer.current = update.current;
+            layer.total = update.total;
+        }
+        layer.status = update.status;
+    }
+
+    fn totals(&self) -> (u64, u64, usize) {
+        self.layers
+            .iter()
+            .fold((0, 0, 0), |(done, total, complete), layer| {
+                (
+                    done + layer.transferred,
+                    total + layer.transfer_total,
+                    complete + usize::from(layer.phase == LayerPhase::Complete),
+                )
+            })
+    }
+
+    /// One-line progress for the status bar while the view is hidden
+    pub fn summary(&self) -> StatusMessage {
+        match &self.status {
+            TransferStatus::Running => {
+                let (done, total, complete) = self.totals();
+                StatusMessage::info(format!(
+                    "{}: {}/{} layers, {} / {}",
+                    self.title,
+                    complete,
+                    self.layers.len(),
+                    DockerClient::format_size(done as i64),
+                    DockerClient::format_size(total as i64)
+                ))
+            }
+            TransferStatus::Finished => StatusMessage::info(format!("{}: done", self.title)),
+            TransferStatus::Failed(e) => StatusMessage::error(format!("{}: {}", self.title, e)),
+            TransferStatus::Cancelled => StatusMessage::error(format!("{}: cancelled", self.title)),
+        }
+    }
+
+    pub fn handle_input(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down => {
+                self.scroll = (self.scroll + 1).min(self.layers.len().saturating_sub(1));
+            }
+            _ => {}
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let theme = current_theme();
+
+        let (border_style, state) = match &self.status {
+            TransferStatus::Running => (theme.modal_border_style(), "running".to_string()),
+            TransferStatus::Finished => (theme.success_style(), "done".to_string()),
+            TransferStatus::Failed(_) => (theme.error_style(), "failed".to_string()),
+            TransferStatus::Cancelled => (theme.warning_style(), "cancelled".to_string()),
+        };
+        let block = Block::default()
+            .title(format!("{} [{}]", self.title, state))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(0)])
+            .split(inner);
+
+        let (done, total, complete) = self.totals();
+        let message = match &self.status {
+            TransferStatus::Failed(e) => Span::styled(e.clone(), theme.error_style()),
+            _ => Span::styled(self.last_message.clone(), theme.muted_style()),
+        };
+        let summary = vec![
+            Line::from(Span::styled(
+                format!(
+                    "{} / {} transferred, {}/{} layers complete",
+                    DockerClient::format_size(done as i64),
+                    DockerClient::format_size(total as i64),
+                    complete,
+                    self.layers.len()
+                ),
+                theme.info_style(),
+            )),
+            Line::from(message),
+        ];
+        f.render_widget(Paragraph::new(summary), chunks[0]);
+
+        // One gauge row per layer
+        let rows = chunks[1].height as usize;
+        for (row, layer) in self.layers.iter().skip(self.scroll).take(rows).enumerate() {
+            let style = match layer.phase {
+                LayerPhase::Waiting => theme.muted_style(),
+                LayerPhase::Transferring => theme.info_style(),
+                LayerPhase::Extracting => theme.warning_style(),
+                LayerPhase::Complete => theme.success_style(),
+            };
+            let label = if layer.total > 0 && layer.phase != LayerPhase::Complete {
+                format!(
+                    "{} {} {} / {}",
+                    layer.id,
+                    layer.status.split_whitespace().next().unwrap_or_default(),
+                    DockerClient::format_size(layer.current as i64),
+                    DockerClient::format_size(layer.total as i64)
+                )
+            } else {
+                format!("{} {}", layer.id, layer.status)
+            };
+
+            let gauge = Gauge::default()
+                .gauge_style(style)
+                .ratio(layer.ratio())
+                .label(label);
+            let row_area = Rect {
+                y: chunks[1].y + row as u16,
+                height: 1,
+                ..chunks[1]
+            };
+            f.render_widget(gauge, row_area);
+        }
+    }
+}
+
+impl Drop for ProgressView {
+    fn drop(&mut self) {
+        self.token.cancel();
+    }
+}
@@ -0,0 +1,391 @@
+use crate::docker::{ContainerInfo, ContainerStats, DockerClient};
+use crate::theme::current_theme;
+
+use futures::StreamExt;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    symbols,
+    text::Line,
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph, Sparkline},
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Instant;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+// Samples kept per container, the daemon sends about one per second
+const HISTORY_LEN: usize = 60;
+
+enum StatsEvent {
+    Sample(String, ContainerStats),
+    Ended(String, u64),
+}
+
+struct StatsStream {
+    token: CancellationToken,
+    generation: u64,
+}
+
+/// Recent samples of one container, with I/O totals turned into per-second rates
+#[derive(Default)]
+pub struct StatsHistory {
+    latest: Option<(ContainerStats, Instant)>,
+    cpu: VecDeque<f64>,
+    memory: VecDeque<u64>,
+    net_rx: VecDeque<u64>,
+    net_tx: VecDeque<u64>,
+    block_read: VecDeque<u64>,
+    block_write: VecDeque<u64>,
+}
+
+impl StatsHistory {
+    pub fn latest(&self) -> Option<&ContainerStats> {
+        self.latest.as_ref().map(|(stats, _)| stats)
+    }
+
+    fn push(&mut self, stats: ContainerStats) {
+        let now = Instant::now();
+        let rate = |current: u64, previous: u64, elapsed: f64| {
+            if elapsed > 0.0 {
+                (current.saturating_sub(previous) as f64 / elapsed) as u64
+            } else {
+                0
+            }
+        };
+
+        let (rx, tx, read, write) = match &self.latest {
+            Some((previous, at)) => {
+                let elapsed = now.duration_since(*at).as_secs_f64();
+                (
+                    rate(stats.net_rx, previous.net_rx, elapsed),
+                    rate(stats.net_tx, previous.net_tx, elapsed),
+                    rate(stats.block_read, previous.block_read, elapsed),
+                    rate(stats.block_write, previous.block_write, elapsed),
+                )
+            }
+            None => (0, 0, 0, 0),
+        };
+
+        Self::push_sample(&mut self.cpu, stats.cpu_percent);
+        Self::push_sample(&mut self.memory, stats.memory_usage);
+        Self::push_sample(&mut self.net_rx, rx);
+        Self::push_sample(&mut self.net_tx, tx);
+        Self::push_sample(&mut self.block_read, read);
+        Self::push_sample(&mut self.block_write, write);
+        self.latest = Some((stats, now));
+    }
+
+    fn push_sample<T>(samples: &mut VecDeque<T>, value: T) {
+        if samples.len() == HISTORY_LEN {
+            samples.pop_front();
+        }
+        samples.push_back(value);
+    }
+}
+
+/// One stats stream per running container, each cancelled when the
+/// container stops or disappears and all of them when the app quits
+pub struct StatsMonitor {
+    streams: HashMap<String, StatsStream>,
+    history: HashMap<String, StatsHistory>,
+    cancellation_token: CancellationToken,
+    next_generation: u64,
+    event_tx: mpsc::UnboundedSender<StatsEvent>,
+    event_rx: mpsc::UnboundedReceiver<StatsEvent>,
+}
+
+impl StatsMonitor {
+    pub fn new(cancellation_token: CancellationToken) -> Self {
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        Self {
+            streams: HashMap::new(),
+            history: HashMap::new(),
+            cancellation_token,
+            next_generation: 0,
+            event_tx,
+            event_rx,
+        }
+    }
+
+    /// Start streams for newly running containers and stop the stale ones
+    pub fn sync(&mut self, client: &DockerClient, containers: &[ContainerInfo]) {
+        let running: HashSet<&str> = containers
+            .iter()
+            .filter(|container| container.state == "running")
+            .map(|container| container.id.as_str())
+            .collect();
+
+        self.streams.retain(|id, stream| {
+            let keep = running.contains(id.as_str());
+            if !keep {
+                stream.token.cancel();
+            }
+            keep
+        });
+        self.history.retain(|id, _| running.contains(id.as_str()));
+
+        for id in running {
+            if !self.streams.contains_key(id) {
+                self.spawn_stream(client.clone(), id.to_string());
+            }
+        }
+    }
+
+    fn spawn_stream(&mut self, client: DockerClient, id: String) {
+        let token = self.cancellation_token.child_token();
+        let generation = self.next_generation;
+        self.next_generation += 1;
+
+        let task_token = token.clone();
+        let event_tx = self.event_tx.clone();
+        let container_id = id.clone();
+
+        tokio::spawn(async move {
+            let mut stream = Box::pin(client.stream_stats(&container_id));
+            loop {
+                tokio::select! {
+                    _ = task_token.cancelled() => break,
+                    sample = stream.next() => match sample {
+                        Some(Ok(stats)) => {
+                            if event_tx
+                                .send(StatsEvent::Sample(container_id.clone(), stats))
+                                .is_err()
+                            {
+                                break;
+                            }
+                        }
+                        _ => break,
+                    },
+                }
+            }
+            drop(stream);
+            let _ = event_tx.send(StatsEvent::Ended(container_id, generation));
+        });
+
+        self.streams.insert(id, StatsStream { token, generation });
+    }
+
+    /// Record samples received since the last tick
+    pub fn poll(&mut self) {
+        while let Ok(event) = self.event_rx.try_recv() {
+            match event {
+                StatsEvent::Sample(id, stats) => {
+                    if self.streams.contains_key(&id) {
+                        self.history.entry(id).or_default().push(stats);
+                    }
+                }
+                StatsEvent::Ended(id, generation) => {
+                    // Let the next sync restart it, unless it was already replaced
+                    if self
+                        .streams
+                        .get(&id)
+                        .is_some_and(|stream| stream.generation == generation)
+                    {
+                        self.streams.remove(&id);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&StatsHistory> {
+        self.history.get(id)
+    }
+
+    pub fn format_cpu(stats: &ContainerStats) -> String {
+        format!("{:.1}%", stats.cpu_percent)
+    }
+
+    pub fn format_memory(stats: &ContainerStats) -> String {
+        format!(
+            "{} / {}",
+            DockerClient::format_size(stats.memory_usage as i64),
+            DockerClient::format_size(stats.memory_limit as i64)
+        )
+    }
+
+    pub fn format_io(first: u64, second: u64) -> String {
+        format!(
+            "{} / {}",
+            DockerClient::format_size(first as i64),
+            DockerClient::format_size(second as i64)
+        )
+    }
+
+    /// Full-tab view with the recent history of the given container
+    pub fn render_detail(&self, f: &mut Frame, area: Rect, container: &ContainerInfo) {
+        let theme = current_theme();
+
+        let block = Block::default()
+            .title(format!("Stats: {}", container.name))
+            .borders(Borders::ALL)
+            .border_style(theme.border_style());
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let Some(history) = self.get(&container.id) else {
+            let message = if container.state == "running" {
+                "Waiting for stats..."
+            } else {
+                "Container is not running"
+            };
+            f.render_widget(Paragraph::new(message).style(theme.muted_style()), inner);
+            return;
+        };
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Percentage(40),
+                Constraint::Percentage(20),
+                Constraint::Percentage(20),
+                Constraint::Percentage(20),
+            ])
+            .split(inner);
+
+        if let Some(stats) = history.latest() {
+            let summary = vec![
+                Line::from(format!(
+                    "CPU {}   Memory {}",
+                    Self::format_cpu(stats),
+                    Self::format_memory(stats)
+                )),
+                Line::from(format!(
+                    "Net I/O {}   Block I/O {}",
+                    Self::format_io(stats.net_rx, stats.net_tx),
+                    Self::format_io(stats.block_read, stats.block_write)
+                )),
+            ];
+            f.render_widget(Paragraph::new(summary).style(theme.info_style()), chunks[0]);
+        }
+
+        self.render_cpu_chart(f, chunks[1], history);
+
+        let memory: Vec<u64> = history.memory.iter().copied().collect();
+        let memory_title = format!(
+            "Memory {}",
+            DockerClient::format_size(memory.last().copied().unwrap_or(0) as i64)
+        );
+        Self::render_sparkline(f, chunks[2], memory_title, &memory);
+
+        Self::render_rate_pair(
+            f,
+            chunks[3],
+            "Net RX",
+            &history.net_rx,
+            "Net TX",
+            &history.net_tx,
+        );
+        Self::render_rate_pair(
+            f,
+            chunks[4],
+            "Block Read",
+            &history.block_read,
+            "Block Write",
+            &history.block_write,
+        );
+    }
+
+    fn render_cpu_chart(&self, f: &mut Frame, area: Rect, history: &StatsHistory) {
+        let theme = current_theme();
+
+        // Right-align the samples so the newest one is always at "now"
+        let offset = HISTORY_LEN - history.cpu.len();
+        let points: Vec<(f64, f64)> = history
+            .cpu
+            .iter()
+            .enumerate()
+            .map(|(i, cpu)| ((offset + i) as f64, *cpu))
+            .collect();
+        let peak = history.cpu.iter().copied().fold(0.0, f64::max);
+        let max = if peak > 100.0 {
+            (peak * 1.1).ceil()
+        } else {
+            100.0
+        };
+
+        let dataset = Dataset::default()
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(theme.running_status_style())
+            .data(&points);
+
+        let chart = Chart::new(vec![dataset])
+            .block(
+                Block::default()
+                    .title(format!(
+                        "CPU {:.1}%",
+                        history.cpu.back().copied().unwrap_or(0.0)
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .x_axis(
+                Axis::default()
+                    .style(theme.muted_style())
+                    .bounds([0.0, (HISTORY_LEN - 1) as f64])
+                    .labels([format!("-{}s", HISTORY_LEN), "now".to_string()]),
+            )
+            .y_axis(
+                Axis::default()
+                    .style(theme.muted_style())
+                    .bounds([0.0, max])
+                    .labels(["0%".to_string(), format!("{:.0}%", max)]),
+            );
+
+        f.render_widget(chart, area);
+    }
+
+    fn render_rate_pair(
+        f: &mut Frame,
+        area: Rect,
+        left_title: &str,
+        left: &VecDeque<u64>,
+        right_title: &str,
+        right: &VecDeque<u64>,
+    ) {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+
+        for (chunk, title, samples) in [
+            (chunks[0], left_title, left),
+            (chunks[1], right_title, right),
+        ] {
+            let samples: Vec<u64> = samples.iter().copied().collect();
+            let title = format!(
+                "{} {}/s",
+                title,
+                DockerClient::format_size(samples.last().copied().unwrap_or(0) as i64)
+            );
+            Self::render_sparkline(f, chunk, title, &samples);
+        }
+    }
+
+    fn render_sparkline(f: &mut Frame, area: Rect, title: String, samples: &[u64]) {
+        let theme = current_theme();
+
+        let sparkline = Sparkline::default()
+            .block(
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .style(theme.info_style())
+            .data(samples);
+
+        f.render_widget(sparkline, area);
+    }
+}
+
+impl Drop for StatsMonitor {
+    fn drop(&mut self) {
+        for stream in self.streams.values() {
+            stream.token.cancel();
+        }
+    }
+}
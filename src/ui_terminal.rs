@@ -0,0 +1,321 @@
+use crate::docker::{ContainerInfo, DockerClient};
+use crate::theme::current_theme;
+
+use bollard::exec::StartExecResults;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use futures::StreamExt;
+use ratatui::{
+    Frame,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::{Notify, mpsc};
+use tokio_util::sync::CancellationToken;
+
+// Written by the exec task, read when rendering
+struct PaneScreen {
+    parser: vt100::Parser,
+    exit_status: Option<String>,
+}
+
+/// Exec session rendered through a VT100 emulator inside the Containers tab
+pub struct TerminalPane {
+    container_name: String,
+    // Output is fed to the emulator as it arrives, so a redraw is enough
+    // to show it and no component has to be ticked
+    screen: Arc<Mutex<PaneScreen>>,
+    input_tx: mpsc::UnboundedSender<Vec<u8>>,
+    resize_tx: mpsc::UnboundedSender<(u16, u16)>,
+    token: CancellationToken,
+    // Inner size seen by the last render, packed as rows << 16 | cols
+    render_size: AtomicU32,
+}
+
+fn lock(screen: &Mutex<PaneScreen>) -> MutexGuard<'_, PaneScreen> {
+    screen.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl TerminalPane {
+    pub fn open(
+        client: DockerClient,
+        container: &ContainerInfo,
+        cancellation_token: &CancellationToken,
+        redraw: Arc<Notify>,
+    ) -> Self {
+        let (input_tx, mut input_rx) = mpsc::unbounded_channel::<Vec<u8>>();
+        let (resize_tx, mut resize_rx) = mpsc::unbounded_channel::<(u16, u16)>();
+        let token = cancellation_token.child_token();
+        let screen = Arc::new(Mutex::new(PaneScreen {
+            parser: vt100::Parser::new(24, 80, 0),
+            exit_status: None,
+        }));
+
+        let container_id = container.id.clone();
+        let task_token = token.clone();
+        let task_screen = Arc::clone(&screen);
+
+        tokio::spawn(async move {
+            let exited = |error: Option<String>| {
+                lock(&task_screen).exit_status = Some(match error {
+                    Some(e) => format!("error: {}", e),
+                    None => "exited".to_string(),
+                });
+                redraw.notify_one();
+            };
+
+            let exec_id = match client
+                .create_exec(&container_id, crate::exec::shell_command(), true)
+                .await
+            {
+                Ok(exec_id) => exec_id,
+                Err(e) => {
+                    exited(Some(e.to_string()));
+                    return;
+                }
+            };
+
+            let (mut output, mut input) = match client.start_exec(&exec_id, true).await {
+                Ok(StartExecResults::Attached { output, input }) => (output, input),
+                Ok(StartExecResults::Detached) => {
+                    exited(None);
+                    return;
+                }
+                Err(e) => {
+                    exited(Some(e.to_string()));
+                    return;
+                }
+            };
+
+            let error = loop {
+                tokio::select! {
+                    _ = task_token.cancelled() => break None,
+                    chunk = output.next() => match chunk {
+                        Some(Ok(chunk)) => {
+                            lock(&task_screen).parser.process(chunk.as_ref());
+                            // Wakes the main loop, redraws pending at once collapse into one
+                            redraw.notify_one();
+                        }
+                        Some(Err(e)) => break Some(e.to_string()),
+                        None => break None,
+                    },
+                    Some(bytes) = input_rx.recv() => {
+                        if input.write_all(&bytes).await.is_err() || input.flush().await.is_err() {
+                            break None;
+                        }
+                    }
+                    Some((rows, cols)) = resize_rx.recv() => {
+                        let _ = client.resize_exec(&exec_id, cols, rows).await;
+                    }
+                }
+            };
+
+            exited(error);
+        });
+
+        Self {
+            container_name: container.name.clone(),
+            screen,
+            input_tx,
+            resize_tx,
+            token,
+            render_size: AtomicU32::new(24 << 16 | 80),
+        }
+    }
+
+    pub fn is_exited(&self) -> bool {
+        lock(&self.screen).exit_status.is_some()
+    }
+
+    /// Follow pane resizes
+    pub fn poll(&mut self) {
+        let packed = self.render_size.load(Ordering::Relaxed);
+        let size = ((packed >> 16) as u16, packed as u16);
+        let mut screen = lock(&self.screen);
+        if size != screen.parser.screen().size() && size.0 > 0 && size.1 > 0 {
+            screen.parser.set_size(size.0, size.1);
+            let _ = self.resize_tx.send(size);
+        }
+    }
+
+    pub fn send_key(&self, key: KeyEvent) {
+        let application_cursor = lock(&self.screen).parser.screen().application_cursor();
+        if let Some(bytes) = Self::key_to_bytes(key, application_cursor) {
+            let _ = self.input_tx.send(bytes);
+        }
+    }
+
+    /// Translate a key event to the bytes a terminal would send
+    fn key_to_bytes(key: KeyEvent, application_cursor: bool) -> Option<Vec<u8>> {
+        let cursor = |code: u8| {
+            if application_cursor {
+                vec![0x1b, b'O', code]
+            } else {
+                vec![0x1b, b'[', code]
+            }
+        };
+
+        let bytes = match key.code {
+            KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                match c.to_ascii_lowercase() {
+                    c @ 'a'..='z' => vec![c as u8 & 0x1f],
+                    '[' => vec![0x1b],
+                    '\\' => vec![0x1c],
+                    ']' => vec![0x1d],
+                    ' ' | '@' => vec![0x00],
+                    _ => return None,
+                }
+            }
+            KeyCode::Char(c) => {
+                let mut bytes = Vec::new();
+                if key.modifiers.contains(KeyModifiers::ALT) {
+                    bytes.push(0x1b);
+                }
+                let mut buf = [0u8; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                bytes
+            }
+            KeyCode::Enter => vec![b'\r'],
+            KeyCode::Backspace => vec![0x7f],
+            KeyCode::Tab => vec![b'\t'],
+            KeyCode::BackTab => b"\x1b[Z".to_vec(),
+            KeyCode::Esc => vec![0x1b],
+            KeyCode::Up => cursor(b'A'),
+            KeyCode::Down => cursor(b'B'),
+            KeyCode::Right => cursor(b'C'),
+            KeyCode::Left => cursor(b'D'),
+            KeyCode::Home => cursor(b'H'),
+            KeyCode::End => cursor(b'F'),
+            KeyCode::PageUp => b"\x1b[5~".to_vec(),
+            KeyCode::PageDown => b"\x1b[6~".to_vec(),
+            KeyCode::Insert => b"\x1b[2~".to_vec(),
+            KeyCode::Delete => b"\x1b[3~".to_vec(),
+            KeyCode::F(n @ 1..=4) => vec![0x1b, b'O', b'P' + n - 1],
+            KeyCode::F(n) => {
+                let code = match n {
+                    5 => 15,
+                    6 => 17,
+                    7 => 18,
+                    8 => 19,
+                    9 => 20,
+                    10 => 21,
+                    11 => 23,
+                    12 => 24,
+                    _ => return None,
+                };
+                format!("\x1b[{}~", code).into_bytes()
+            }
+            _ => return None,
+        };
+
+        Some(bytes)
+    }
+
+    fn convert_color(color: vt100::Color) -> Option<Color> {
+        match color {
+            vt100::Color::Default => None,
+            vt100::Color::Idx(i) => Some(Color::Indexed(i)),
+            vt100::Color::Rgb(r, g, b) => Some(Color::Rgb(r, g, b)),
+        }
+    }
+
+    fn cell_style(cell: &vt100::Cell) -> Style {
+        let theme = current_theme();
+        let mut style = theme.normal_style();
+
+        if let Some(fg) = Self::convert_color(cell.fgcolor()) {
+            style = style.fg(fg);
+        }
+        if let Some(bg) = Self::convert_color(cell.bgcolor()) {
+            style = style.bg(bg);
+        }
+        if cell.bold() {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        if cell.italic() {
+            style = style.add_modifier(Modifier::ITALIC);
+        }
+        if cell.underline() {
+            style = style.add_modifier(Modifier::UNDERLINED);
+        }
+        if cell.inverse() {
+            style = style.add_modifier(Modifier::REVERSED);
+        }
+
+        style
+    }
+
+    pub fn render(&self, f: &mut Frame, area: ratatui::layout::Rect, focused: bool) {
+        let theme = current_theme();
+
+        let pane_screen = lock(&self.screen);
+        let status = match &pane_screen.exit_status {
+            Some(status) => format!(" [{}]", status),
+            None if focused => " [focused, F2 to release, F3 to close]".to_string(),
+            None => " [F2 to focus, F3 to close]".to_string(),
+        };
+        let block = Block::default()
+            .title(format!("Shell: {}{}", self.container_name, status))
+            .borders(Borders::ALL)
+            .border_style(if focused {
+                theme.modal_border_style()
+            } else {
+                theme.border_style()
+            });
+
+        let inner = block.inner(area);
+        self.render_size.store(
+            (inner.height as u32) << 16 | inner.width as u32,
+            Ordering::Relaxed,
+        );
+
+        let screen = pane_screen.parser.screen();
+        let (rows, cols) = screen.size();
+        let (cursor_row, cursor_col) = screen.cursor_position();
+        let show_cursor = focused && !screen.hide_cursor() && pane_screen.exit_status.is_none();
+
+        let lines: Vec<Line> = (0..rows.min(inner.height))
+            .map(|row| {
+                let mut spans: Vec<Span> = Vec::new();
+                for col in 0..cols.min(inner.width) {
+                    let Some(cell) = screen.cell(row, col) else {
+                        continue;
+                    };
+                    if cell.is_wide_continuation() {
+                        continue;
+                    }
+
+                    let mut style = Self::cell_style(cell);
+                    if show_cursor && row == cursor_row && col == cursor_col {
+                        style = style.add_modifier(Modifier::REVERSED);
+                    }
+                    let contents = if cell.has_contents() {
+                        cell.contents()
+                    } else {
+                        " ".to_string()
+                    };
+
+                    // Merge runs of equally styled cells
+                    match spans.last_mut() {
+                        Some(last) if last.style == style => {
+                            last.content.to_mut().push_str(&contents);
+                        }
+                        _ => spans.push(Span::styled(contents, style)),
+                    }
+                }
+                Line::from(spans)
+            })
+            .collect();
+
+        f.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+impl Drop for TerminalPane {
+    fn drop(&mut self) {
+        self.token.cancel();
+    }
+}
@@ -1,13 +1,15 @@
-use crate::components::Component;
+use crate::components::{Component, StatusMessage};
 use crate::docker::DockerClient;
 use crate::theme::current_theme;
+use crate::ui_json::JsonView;
 
 use async_trait::async_trait;
 use color_eyre::Result;
 use crossterm::event::KeyCode;
 use ratatui::{
     Frame,
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    layout::{Alignment, Constraint, Direction, Layout},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
 };
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -18,6 +20,9 @@ pub struct VolumesUI {
     selected_index: usize,
     volumes: Vec<String>,
     last_tick: std::time::Instant,
+    // Inspect modal, shows the raw JSON returned by the daemon
+    json_view: Option<JsonView>,
+    status_message: Option<StatusMessage>,
 }
 
 impl VolumesUI {
@@ -28,6 +33,8 @@ impl VolumesUI {
             selected_index: 0,
             volumes: Vec::new(),
             last_tick: std::time::Instant::now(),
+            json_view: None,
+            status_message: None,
         }
     }
 
@@ -67,12 +74,47 @@ impl VolumesUI {
         Ok(())
     }
 
-    async fn inspect_volume(&self, volume_name: &str) -> Result<()> {
-        eprintln!("Inspecting volume: {}", volume_name);
-        // TODO: Implement volume inspection
-        // Show detailed info including mountpoint and usage
+    async fn inspect_volume(&mut self, volume_name: &str) -> Result<()> {
+        let client = self.docker_client.lock().await;
+        match client.inspect_volume_json(volume_name).await {
+            Ok(value) => {
+                self.json_view = Some(JsonView::new(
+                    format!("Volume Inspection: {}", volume_name),
+                    value,
+                ));
+            }
+            Err(e) => {
+                self.status_message = Some(StatusMessage::error(format!(
+                    "Failed to inspect volume {}: {}",
+                    volume_name,
+                    DockerClient::format_error(&e)
+                )));
+            }
+        }
         Ok(())
     }
+
+    fn render_inspect_modal(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let Some(json_view) = &self.json_view else {
+            return;
+        };
+        let popup_area = crate::ui::centered_rect(80, 80, area);
+
+        // Clear the background
+        f.render_widget(Clear, popup_area);
+
+        let content_area = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(popup_area);
+
+        json_view.render(f, content_area[0]);
+
+        let help = Paragraph::new("[↑/↓] Move   [Y] Copy Field   [Shift+Y] Copy All   [Esc] Close")
+            .style(current_theme().muted_style())
+            .alignment(Alignment::Center);
+        f.render_widget(help, content_area[1]);
+    }
 }
 
 #[async_trait]
@@ -95,9 +137,27 @@ impl Component for VolumesUI {
             self.last_tick = now;
             let _ = self.refresh_now().await;
         }
+
+        if self
+            .status_message
+            .as_ref()
+            .is_some_and(|message| message.is_expired())
+        {
+            self.status_message = None;
+        }
     }
 
     async fn handle_input(&mut self, key: KeyCode) -> Result<bool> {
+        // Handle modal input first
+        if let Some(json_view) = &mut self.json_view {
+            if key == KeyCode::Esc {
+                self.json_view = None;
+            } else {
+                json_view.handle_input(key);
+            }
+            return Ok(true); // Modal is open, consume all events
+        }
+
         match key {
             KeyCode::Up => {
                 if self.selected_index > 0 {
@@ -128,7 +188,8 @@ impl Component for VolumesUI {
             }
             KeyCode::Char('i') => {
                 if let Some(volume_name) = self.get_selected_volume() {
-                    self.inspect_volume(volume_name).await?;
+                    let volume_name = volume_name.clone();
+                    self.inspect_volume(&volume_name).await?;
                 }
                 Ok(true)
             }
@@ -139,6 +200,20 @@ impl Component for VolumesUI {
     fn render(&self, f: &mut Frame, area: ratatui::layout::Rect) {
         let theme = current_theme();
 
+        // Reserve a line for the status message if there is one
+        let list_area = if let Some(message) = &self.status_message {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(1)])
+                .split(area);
+
+            let status = Paragraph::new(message.text.clone()).style(message.style());
+            f.render_widget(status, chunks[1]);
+            chunks[0]
+        } else {
+            area
+        };
+
         if self.volumes.is_empty() {
             let paragraph = Paragraph::new("No volumes found or loading...")
                 .block(
@@ -148,7 +223,7 @@ impl Component for VolumesUI {
                         .border_style(theme.border_style()),
                 )
                 .style(theme.muted_style());
-            f.render_widget(paragraph, area);
+            f.render_widget(paragraph, list_area);
         } else {
             let items: Vec<ListItem> = self
                 .volumes
@@ -173,11 +248,17 @@ impl Component for VolumesUI {
                 )
                 .style(theme.normal_style());
 
-            f.render_widget(list, area);
+            f.render_widget(list, list_area);
         }
+
+        // Render modal if active
+        self.render_inspect_modal(f, area);
     }
 
     fn render_help(&self) -> &'static str {
+        if self.json_view.is_some() {
+            return "[↑/↓/PgUp/PgDn] Move   [Y] Copy Field   [Shift+Y] Copy All   [Esc] Close";
+        }
         "[↑/↓] Select   [C] Create   [D] Delete   [I] Inspect   [R/F5] Refresh   [Q] Quit"
     }
 }